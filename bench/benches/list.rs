@@ -0,0 +1,98 @@
+// Benchmark daftar tugas besar: filter, sort, visible_indices dan render ke
+// TestBackend, di atas data dari App::with_bench_data. Jalankan dari folder
+// `bench` dengan `cargo bench`.
+
+use criterion::{criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion};
+use fauzyrustproject::{ui, App, Priority};
+use ratatui::{backend::TestBackend, Terminal};
+use std::hint::black_box;
+
+const COUNTS: [usize; 2] = [1_000, 50_000];
+
+// Prioritas campur supaya sort benar-benar memindahkan tugas
+fn bench_app(count: usize) -> App {
+    let mut app = App::with_bench_data(count);
+    for tab in app.tabs.iter_mut() {
+        for (index, task) in tab.list.items.iter_mut().enumerate() {
+            task.priority = match index % 4 {
+                0 => Priority::None,
+                1 => Priority::Low,
+                2 => Priority::Medium,
+                _ => Priority::High,
+            };
+        }
+    }
+    app
+}
+
+fn filtered_app(count: usize) -> App {
+    let mut app = bench_app(count);
+    app.current_tab_mut().filter = "task 12".to_string();
+    app
+}
+
+fn visible_indices(c: &mut Criterion) {
+    let mut group = c.benchmark_group("visible_indices");
+    for count in COUNTS {
+        // Cache dibuang setiap iterasi, jadi yang diukur pencocokan filternya
+        let mut app = filtered_app(count);
+        group.bench_function(BenchmarkId::new("filtered", count), |b| {
+            b.iter(|| {
+                let tab = app.current_tab_mut();
+                tab.invalidate_caches();
+                black_box(tab.visible_indices());
+            })
+        });
+        let mut app = bench_app(count);
+        group.bench_function(BenchmarkId::new("no filter", count), |b| {
+            b.iter(|| {
+                let tab = app.current_tab_mut();
+                tab.invalidate_caches();
+                black_box(tab.visible_indices());
+            })
+        });
+        let app = filtered_app(count);
+        group.bench_function(BenchmarkId::new("cached", count), |b| {
+            b.iter(|| black_box(app.current_tab().visible_indices()))
+        });
+    }
+    group.finish();
+}
+
+fn sort_by_priority(c: &mut Criterion) {
+    let mut group = c.benchmark_group("sort_by_priority");
+    for count in COUNTS {
+        group.bench_function(BenchmarkId::from_parameter(count), |b| {
+            b.iter_batched(
+                || bench_app(count),
+                |mut app| {
+                    app.sort_by_priority();
+                    app
+                },
+                BatchSize::LargeInput,
+            )
+        });
+    }
+    group.finish();
+}
+
+fn draw(c: &mut Criterion) {
+    let mut group = c.benchmark_group("draw");
+    let mut terminal = Terminal::new(TestBackend::new(120, 40)).expect("test terminal");
+    for count in COUNTS {
+        for (name, mut app) in [
+            ("no filter", bench_app(count)),
+            ("filtered", filtered_app(count)),
+        ] {
+            group.bench_function(BenchmarkId::new(name, count), |b| {
+                b.iter(|| {
+                    terminal.draw(|f| ui::draw(f, &mut app)).expect("draw");
+                })
+            });
+        }
+    }
+    group.finish();
+}
+
+criterion_group!(benches, visible_indices, sort_by_priority, draw);
+criterion_main!(benches);
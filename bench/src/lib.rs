@@ -0,0 +1 @@
+// Kosong: crate ini hanya wadah benchmark di `benches/`
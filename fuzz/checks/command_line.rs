@@ -0,0 +1,48 @@
+// Setiap baris input diketik di baris perintah `:` lalu dijalankan. Perintah yang
+// menyentuh file (export/import) dan quit dilewati. Setelah tiap perintah layar
+// digambar ulang dan state harus tetap konsisten.
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use fauzyrustproject::{
+    command::{self, Command},
+    event::handle_key,
+    ui, App,
+};
+use ratatui::{backend::TestBackend, Terminal};
+
+fn press(app: &mut App, code: KeyCode) {
+    handle_key(app, KeyEvent::new(code, KeyModifiers::NONE));
+}
+
+pub fn check(data: &[u8]) {
+    let Ok(text) = std::str::from_utf8(data) else {
+        return;
+    };
+    let mut app = App::new();
+    let mut terminal = Terminal::new(TestBackend::new(80, 24)).unwrap();
+    for line in text.lines().take(64) {
+        if matches!(
+            command::parse(line),
+            Ok(Command::Export { .. } | Command::Import(_) | Command::Quit)
+        ) {
+            continue;
+        }
+        // Saran dihitung ulang di setiap ketikan, seperti saat popup tampil
+        let _ = app.command_completions();
+        press(&mut app, KeyCode::Char(':'));
+        for c in line.chars() {
+            press(&mut app, KeyCode::Char(c));
+        }
+        let _ = app.command_completions();
+        press(&mut app, KeyCode::Enter);
+        // Perintah seperti :tabclose atau :search membuka mode lain; tutup lagi
+        press(&mut app, KeyCode::Esc);
+
+        terminal.draw(|f| ui::draw(f, &mut app)).unwrap();
+        assert!(app.active_tab < app.tabs.len());
+        for tab in &app.tabs {
+            let selected = tab.list.state.selected();
+            assert!(selected.map_or(true, |index| index < tab.list.items.len()));
+        }
+    }
+}
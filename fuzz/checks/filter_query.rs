@@ -0,0 +1,41 @@
+// Baris pertama jadi query filter, baris kedua tag filter, sisanya judul tugas.
+// Navigasi dan render dengan filter apa pun tidak boleh panic atau membuat
+// seleksi menunjuk ke tugas yang tersembunyi.
+
+use fauzyrustproject::{ui, App, TabData, Task};
+use ratatui::{backend::TestBackend, Terminal};
+
+pub fn check(data: &[u8]) {
+    let Ok(text) = std::str::from_utf8(data) else {
+        return;
+    };
+    let mut lines = text.lines();
+    let query = lines.next().unwrap_or_default().to_string();
+    let tag = lines
+        .next()
+        .filter(|tag| !tag.is_empty())
+        .map(str::to_string);
+    let tasks: Vec<Task> = lines.take(256).map(Task::new).collect();
+
+    let mut app = App::with_tabs(vec![TabData::new("Fuzz", tasks)]);
+    let mut terminal = Terminal::new(TestBackend::new(60, 20)).unwrap();
+    app.current_tab_mut().filter = query;
+    app.current_tab_mut().tag_filter = tag;
+    app.show_tags = true;
+
+    let visible = app.current_tab().visible_indices();
+    for step in 0..8 {
+        match step % 4 {
+            0 => app.select_next(true),
+            1 => app.page_selection(true),
+            2 => app.select_edge(true),
+            _ => app.select_next(false),
+        }
+        app.keep_selection_visible();
+        terminal.draw(|f| ui::draw(f, &mut app)).unwrap();
+        if let Some(selected) = app.current_tab().list.state.selected() {
+            assert!(visible.contains(&selected));
+        }
+    }
+    let _ = app.current_tab_mut().tag_counts();
+}
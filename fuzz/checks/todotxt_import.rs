@@ -0,0 +1,52 @@
+// File todo.txt apa pun harus bisa diimpor tanpa panic, tanpa tugas berjudul
+// kosong, dan hasilnya harus sama persis setelah diekspor lalu diimpor lagi.
+
+use fauzyrustproject::{export::todotxt, storage::TabRecord};
+
+// Bagian yang disimpan todo.txt; catatan dan jam tidak ikut. Nama tab hanya ditulis
+// kalau ada lebih dari satu tab, dan tugas selesai tidak membawa tanggal dibuat.
+fn fields(tabs: &[TabRecord]) -> Vec<(String, Vec<String>)> {
+    tabs.iter()
+        .map(|tab| {
+            let tasks = tab
+                .tasks
+                .iter()
+                .map(|task| {
+                    format!(
+                        "{:?} {} {:?} {:?} {:?}",
+                        task.title,
+                        task.done,
+                        task.priority,
+                        task.due,
+                        task.created
+                            .filter(|_| !task.done)
+                            .map(|created| created.date_naive())
+                    )
+                })
+                .collect();
+            let name = if tabs.len() > 1 { tab.name.clone() } else { String::new() };
+            (name, tasks)
+        })
+        .collect()
+}
+
+pub fn check(data: &[u8]) {
+    let Ok(text) = std::str::from_utf8(data) else {
+        return;
+    };
+    let imported = todotxt::parse(text, "todo");
+    for tab in &imported {
+        for task in &tab.tasks {
+            assert!(!task.title.trim().is_empty(), "empty title from {:?}", text);
+        }
+    }
+    let rendered = todotxt::render(&imported);
+    let again = todotxt::parse(&rendered, "todo");
+    assert_eq!(
+        fields(&imported),
+        fields(&again),
+        "round trip changed {:?} (rendered {:?})",
+        text,
+        rendered
+    );
+}
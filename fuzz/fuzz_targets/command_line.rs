@@ -0,0 +1,9 @@
+#![no_main]
+
+// Isi pemeriksaan ada di checks/command_line.rs, yang juga dijalankan oleh
+// tests/fuzz_checks.rs di crate utama
+use libfuzzer_sys::fuzz_target;
+
+include!("../checks/command_line.rs");
+
+fuzz_target!(|data: &[u8]| check(data));
@@ -0,0 +1,9 @@
+#![no_main]
+
+// Isi pemeriksaan ada di checks/filter_query.rs, yang juga dijalankan oleh
+// tests/fuzz_checks.rs di crate utama
+use libfuzzer_sys::fuzz_target;
+
+include!("../checks/filter_query.rs");
+
+fuzz_target!(|data: &[u8]| check(data));
@@ -0,0 +1,1897 @@
+use crate::{
+    command::{self, Command, PriorityChange},
+    due,
+    export::{self, todotxt},
+    history::{Action, History},
+    journal::{Entry, Journal},
+    keymap::{KeyChord, Keymap},
+    notes::{NoteFile, Notes},
+    storage,
+    theme::{Pane, Theme},
+    ui,
+};
+use chrono::{DateTime, Local, NaiveDate};
+use ratatui::{layout::Rect, widgets::ListState};
+use serde::{Deserialize, Deserializer, Serialize};
+use std::{
+    cell::RefCell,
+    collections::{BTreeMap, HashSet, VecDeque},
+    fs, io, mem,
+    path::Path,
+    rc::Rc,
+    time::{Duration, Instant},
+};
+
+// Struktur untuk daftar tugas dengan stateful
+pub struct StatefulList<T> {
+    pub state: ListState,
+    pub items: Vec<T>,
+}
+
+impl<T> StatefulList<T> {
+    pub fn new(items: Vec<T>) -> Self {
+        Self {
+            state: ListState::default(),
+            items,
+        }
+    }
+
+    pub fn next(&mut self) {
+        if self.items.is_empty() {
+            return;
+        }
+        let i = match self.state.selected() {
+            Some(i) => {
+                if i >= self.items.len() - 1 {
+                    0
+                } else {
+                    i + 1
+                }
+            }
+            None => 0,
+        };
+        self.state.select(Some(i));
+    }
+
+    pub fn previous(&mut self) {
+        if self.items.is_empty() {
+            return;
+        }
+        let i = match self.state.selected() {
+            Some(i) => {
+                if i == 0 {
+                    self.items.len() - 1
+                } else {
+                    (i - 1).min(self.items.len() - 1)
+                }
+            }
+            None => 0,
+        };
+        self.state.select(Some(i));
+    }
+
+    // Sisipkan item (index dibatasi ke akhir daftar); seleksi tetap di item yang sama
+    pub fn insert(&mut self, index: usize, item: T) -> usize {
+        let index = index.min(self.items.len());
+        self.items.insert(index, item);
+        if let Some(i) = self.state.selected() {
+            if i >= index {
+                self.state.select(Some(i + 1));
+            }
+        }
+        index
+    }
+
+    // Hapus item; seleksi tetap di item yang sama atau pindah ke tetangganya
+    pub fn remove(&mut self, index: usize) -> Option<T> {
+        if index >= self.items.len() {
+            return None;
+        }
+        let item = self.items.remove(index);
+        let selected = match self.state.selected() {
+            _ if self.items.is_empty() => None,
+            Some(i) if i > index => Some(i - 1),
+            Some(i) => Some(i.min(self.items.len() - 1)),
+            None => None,
+        };
+        self.state.select(selected);
+        Some(item)
+    }
+
+    // Tukar dua item; seleksi ikut item yang dipindah
+    pub fn swap(&mut self, a: usize, b: usize) -> bool {
+        if a >= self.items.len() || b >= self.items.len() {
+            return false;
+        }
+        self.items.swap(a, b);
+        match self.state.selected() {
+            Some(i) if i == a => self.state.select(Some(b)),
+            Some(i) if i == b => self.state.select(Some(a)),
+            _ => {}
+        }
+        true
+    }
+
+    pub fn replace(&mut self, index: usize, item: T) -> Option<T> {
+        self.items
+            .get_mut(index)
+            .map(|slot| mem::replace(slot, item))
+    }
+
+    // Jaga seleksi dan offset tetap valid setelah ukuran terminal berubah
+    pub fn clamp(&mut self, visible_rows: usize) {
+        if self.items.is_empty() {
+            self.state.select(None);
+            return;
+        }
+        if let Some(i) = self.state.selected() {
+            if i >= self.items.len() {
+                self.state.select(Some(self.items.len() - 1));
+            }
+        }
+        let max_offset = self.items.len().saturating_sub(visible_rows.max(1));
+        let offset = self.state.offset_mut();
+        *offset = (*offset).min(max_offset);
+    }
+}
+
+// Statistik untuk overlay debug
+pub struct DebugStats {
+    pub visible: bool,
+    pub last_frame: Duration,
+    pub frames: u64,
+    pub recent_events: VecDeque<Instant>,
+}
+
+impl DebugStats {
+    pub fn new(visible: bool) -> Self {
+        Self {
+            visible,
+            last_frame: Duration::ZERO,
+            frames: 0,
+            recent_events: VecDeque::new(),
+        }
+    }
+
+    pub fn record_event(&mut self) {
+        let now = Instant::now();
+        self.recent_events.push_back(now);
+        while let Some(&oldest) = self.recent_events.front() {
+            if now.duration_since(oldest) > Duration::from_secs(1) {
+                self.recent_events.pop_front();
+            } else {
+                break;
+            }
+        }
+    }
+
+    pub fn events_per_sec(&self) -> usize {
+        self.recent_events.len()
+    }
+}
+
+// Satu tugas; tugas selesai tetap disimpan dan dirender dicoret
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Task {
+    pub title: String,
+    #[serde(default)]
+    pub done: bool,
+    // Hanya dipakai di tab yang punya daftar status sendiri
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "deserialize_status"
+    )]
+    pub status: Option<Rc<str>>,
+    // Teksnya lewat App::note_text; yang sudah disimpan ada di file catatan
+    #[serde(default, skip_serializing_if = "Notes::is_empty")]
+    pub notes: Notes,
+    // Kosong untuk tugas dari file versi lama
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created: Option<DateTime<Local>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub due: Option<NaiveDate>,
+    #[serde(default, skip_serializing_if = "Priority::is_none")]
+    pub priority: Priority,
+}
+
+thread_local! {
+    // Nama status yang sudah dipakai; tugas dengan status yang sama berbagi satu salinan
+    static STATUS_NAMES: RefCell<HashSet<Rc<str>>> = RefCell::new(HashSet::new());
+}
+
+pub fn intern_status(name: &str) -> Rc<str> {
+    STATUS_NAMES.with(|names| {
+        let mut names = names.borrow_mut();
+        if let Some(name) = names.get(name) {
+            return name.clone();
+        }
+        let name: Rc<str> = Rc::from(name);
+        names.insert(name.clone());
+        name
+    })
+}
+
+fn deserialize_status<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<Rc<str>>, D::Error> {
+    Ok(Option::<String>::deserialize(deserializer)?.map(|name| intern_status(&name)))
+}
+
+// Urutan varian dipakai untuk sorting: High paling atas
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum Priority {
+    #[default]
+    None,
+    Low,
+    Medium,
+    High,
+}
+
+impl Priority {
+    pub fn is_none(&self) -> bool {
+        *self == Priority::None
+    }
+
+    // None -> Low -> Medium -> High -> None
+    pub fn cycle(self, up: bool) -> Self {
+        const ORDER: [Priority; 4] = [
+            Priority::None,
+            Priority::Low,
+            Priority::Medium,
+            Priority::High,
+        ];
+        let index = self as usize;
+        let next = if up {
+            index + 1
+        } else {
+            index + ORDER.len() - 1
+        };
+        ORDER[next % ORDER.len()]
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Priority::None => "None",
+            Priority::Low => "Low",
+            Priority::Medium => "Medium",
+            Priority::High => "High",
+        }
+    }
+}
+
+impl Task {
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            done: false,
+            status: None,
+            notes: Notes::default(),
+            created: Some(Local::now()),
+            due: None,
+            priority: Priority::None,
+        }
+    }
+
+    // Kata berawalan # di judul, tanpa # dan dalam huruf kecil
+    pub fn tags(&self) -> Vec<String> {
+        self.title
+            .split_whitespace()
+            .filter_map(tag_name)
+            .map(str::to_lowercase)
+            .collect()
+    }
+
+    // Byte di heap untuk teks tugas (judul, catatan yang dimuat); nama status
+    // dipakai bersama, jadi tidak dihitung per tugas
+    pub fn heap_size(&self) -> usize {
+        self.title.capacity() + self.notes.heap_size()
+    }
+
+    // Label status untuk panel detail
+    pub fn status_label(&self, statuses: &[String]) -> String {
+        if statuses.is_empty() {
+            let label = if self.done { "Done" } else { "Open" };
+            label.to_string()
+        } else {
+            statuses[self.status_index(statuses)].clone()
+        }
+    }
+
+    // Status tugas di dalam `statuses`; tanpa status dianggap status pertama
+    // (atau terakhir kalau sudah selesai)
+    pub fn status_index(&self, statuses: &[String]) -> usize {
+        self.status
+            .as_ref()
+            .and_then(|status| statuses.iter().position(|s| **s == **status))
+            .unwrap_or(if self.done { statuses.len() - 1 } else { 0 })
+    }
+
+    // Status terakhir berarti selesai
+    pub fn set_status(&mut self, statuses: &[String], index: usize) {
+        self.status = Some(intern_status(&statuses[index]));
+        self.done = index == statuses.len() - 1;
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InputMode {
+    Normal,
+    Adding,
+    Editing(usize), // Index tugas yang sedang diedit
+    EditingNotes(usize),
+    NewTab,
+    RenamingTab,
+    Filtering,
+    GlobalSearch(usize), // Posisi kursor di daftar hasil
+    Command,
+    ShowingQr,
+    ShowingHelp(u16), // Scroll popup bantuan
+    ConfirmDeleteTab,
+    SwitchingTab(usize), // Posisi kursor di popup tab terakhir
+    PickingTab { copy: bool, cursor: usize }, // Tujuan pindah/salin tugas
+    Tagging(usize),      // Index tugas yang diberi/dilepas tagnya
+    TagSidebar(usize),   // Fokus di sidebar tag; 0 = "semua tugas", 1.. = tag
+}
+
+// Satu tab: nama, daftar tugas, dan state tampilannya
+pub struct TabData {
+    pub name: String,
+    pub list: StatefulList<Task>,
+    pub statuses: Vec<String>, // Kosong = cukup selesai/belum
+    pub show_details: bool,
+    // Query filter `/`; tidak disimpan ke disk
+    pub filter: String,
+    // Scroll daftar hasil filter. Seleksi tetap di `list.state` sebagai index asli,
+    // jadi toggle/hapus/edit selalu mengenai tugas yang benar
+    pub filter_view: ListState,
+    // Tag yang dipilih di sidebar tag; ikut menyaring daftar bersama `filter`
+    pub tag_filter: Option<String>,
+    // Hasil `tag_counts`, dibuang setiap ada perubahan tugas (App::log/apply)
+    tag_cache: Option<Vec<(String, usize)>>,
+    // Hasil `visible_indices` terakhir; dibuang bersama `tag_cache`
+    visible_cache: RefCell<Option<VisibleCache>>,
+}
+
+// Filter dan tag yang menghasilkan `indices`, ditambah jumlah tugas saat itu
+// sebagai pengaman kalau `list.items` diubah tanpa lewat App
+struct VisibleCache {
+    filter: String,
+    tag: Option<String>,
+    len: usize,
+    indices: Rc<[usize]>,
+}
+
+impl TabData {
+    pub fn new(name: impl Into<String>, tasks: Vec<Task>) -> Self {
+        Self {
+            name: name.into(),
+            list: StatefulList::new(tasks),
+            statuses: Vec::new(),
+            show_details: false,
+            filter: String::new(),
+            filter_view: ListState::default(),
+            tag_filter: None,
+            tag_cache: None,
+            visible_cache: RefCell::new(None),
+        }
+    }
+
+    // Index asli tugas yang cocok dengan filter (semua kalau filter kosong), urut
+    // naik. Dipanggil berkali-kali per frame dan per tombol, jadi hasilnya disimpan
+    // selama filter, tag dan isi tab tidak berubah.
+    pub fn visible_indices(&self) -> Rc<[usize]> {
+        let mut cache = self.visible_cache.borrow_mut();
+        if let Some(cache) = cache.as_ref().filter(|cache| {
+            cache.filter == self.filter
+                && cache.tag == self.tag_filter
+                && cache.len == self.list.items.len()
+        }) {
+            return Rc::clone(&cache.indices);
+        }
+        let indices: Rc<[usize]> = self.matching_indices().into();
+        *cache = Some(VisibleCache {
+            filter: self.filter.clone(),
+            tag: self.tag_filter.clone(),
+            len: self.list.items.len(),
+            indices: Rc::clone(&indices),
+        });
+        indices
+    }
+
+    fn matching_indices(&self) -> Vec<usize> {
+        (0..self.list.items.len())
+            .filter(|&index| {
+                let task = &self.list.items[index];
+                fuzzy_match(&task.title, &self.filter)
+                    && self
+                        .tag_filter
+                        .as_ref()
+                        .map_or(true, |tag| task.tags().contains(tag))
+            })
+            .collect()
+    }
+
+    pub fn is_filtered(&self) -> bool {
+        !self.filter.is_empty() || self.tag_filter.is_some()
+    }
+
+    // Semua tag di tab ini dengan jumlah tugasnya, urut nama. Dihitung sekali lalu
+    // disimpan, karena sidebar tag membacanya di setiap frame.
+    pub fn tag_counts(&mut self) -> &[(String, usize)] {
+        if self.tag_cache.is_none() {
+            self.tag_cache = Some(self.count_tags());
+        }
+        self.tag_cache.as_deref().unwrap_or_default()
+    }
+
+    // Wajib dipanggil kalau `list.items` diubah langsung, bukan lewat App
+    pub fn invalidate_caches(&mut self) {
+        self.tag_cache = None;
+        *self.visible_cache.get_mut() = None;
+    }
+
+    fn count_tags(&self) -> Vec<(String, usize)> {
+        let mut counts: BTreeMap<String, usize> = BTreeMap::new();
+        for task in &self.list.items {
+            let mut tags = task.tags();
+            tags.sort();
+            tags.dedup();
+            for tag in tags {
+                *counts.entry(tag).or_default() += 1;
+            }
+        }
+        counts.into_iter().collect()
+    }
+}
+
+// Isi awal input edit: judul plus jatuh tempo dalam format quick entry, supaya
+// tanggalnya bisa diganti atau dihapus bersama judulnya
+fn edit_text(task: &Task) -> String {
+    match task.due {
+        Some(due) => format!("{} {}", task.title, due.format("%Y-%m-%d")),
+        None => task.title.clone(),
+    }
+}
+
+// Tambah " #tag" di akhir judul, atau hapus kata itu kalau tag sudah ada
+fn toggle_tag(title: &mut String, tag: &str) {
+    let tag = tag.trim().trim_start_matches('#').to_lowercase();
+    if tag.is_empty() || tag.contains(char::is_whitespace) {
+        return;
+    }
+    let words: Vec<&str> = title.split_whitespace().collect();
+    let kept: Vec<&str> = words
+        .iter()
+        .copied()
+        .filter(|word| tag_name(word).map(str::to_lowercase).as_deref() != Some(tag.as_str()))
+        .collect();
+    *title = if kept.len() == words.len() {
+        format!("{} #{}", title.trim_end(), tag)
+    } else {
+        kept.join(" ")
+    };
+}
+
+// "#home" -> "home"; "#" saja bukan tag
+pub fn tag_name(word: &str) -> Option<&str> {
+    word.strip_prefix('#').filter(|name| !name.is_empty())
+}
+
+// Cocok kalau semua huruf query muncul berurutan di teks, tanpa beda huruf besar/kecil
+fn fuzzy_match(text: &str, query: &str) -> bool {
+    let mut chars = text.chars().flat_map(char::to_lowercase);
+    query
+        .chars()
+        .flat_map(char::to_lowercase)
+        .all(|q| chars.any(|c| c == q))
+}
+
+// Lama animasi transisi (kalau `animations` aktif di config)
+pub const ANIMATION_DURATION: Duration = Duration::from_millis(180);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Animation {
+    TabSlide,  // Daftar tugas bergeser masuk setelah ganti tab
+    PopupFade, // Popup muncul lewat karakter ░▒▓
+}
+
+// Posisi scroll panel detail; kembali ke atas saat tugas terpilih berganti
+#[derive(Debug, Default)]
+pub struct DetailsScroll {
+    pub offset: u16,
+    pub page: u16,                    // Baris yang terlihat, diisi saat render
+    pub task: Option<(usize, usize)>, // (tab, tugas) yang sedang ditampilkan
+}
+
+// Posisi bagian layar di frame terakhir, diisi saat render untuk klik mouse
+#[derive(Debug, Default)]
+pub struct HitAreas {
+    pub tabs: Vec<Rect>,    // Judul tiap tab, urut sesuai index
+    pub list: Rect,         // Bagian dalam daftar tugas
+    pub list_offset: usize, // Baris pertama yang terlihat (posisi di daftar hasil filter)
+    pub details: Option<Rect>,
+}
+
+// Rincian perkiraan memori dalam byte
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MemoryReport {
+    pub structs: usize, // Struct tab dan tugas, termasuk kapasitas Vec yang belum terpakai
+    pub text: usize,    // Judul, catatan, status, dan nama tab
+    pub history: usize, // Riwayat undo/redo
+}
+
+impl MemoryReport {
+    pub fn total(&self) -> usize {
+        self.structs + self.text + self.history
+    }
+}
+
+// Jarak maksimum dua klik di baris yang sama untuk dihitung double-click
+const DOUBLE_CLICK: Duration = Duration::from_millis(400);
+
+pub struct App {
+    pub tabs: Vec<TabData>, // Selalu berisi minimal satu tab
+    pub active_tab: usize,
+    pub recent_tabs: Vec<usize>, // Index tab, yang terbaru di depan
+    pub input_mode: InputMode,
+    pub input: String,
+    pub privacy_mode: bool,
+    pub show_tags: bool,     // Sidebar tag di kiri daftar tugas
+    pub edit_in_place: bool, // `e` mengedit baris terpilih, bukan lewat kotak input
+    pub keymap: Keymap,
+    pub pending_keys: Vec<KeyChord>, // Urutan vim yang belum selesai, misalnya "d" dari "dd"
+    pub animations: bool,
+    pub animation: Option<(Animation, Instant)>,
+    pub themes: Vec<Theme>, // Tema bawaan, plus tema "custom" dari config
+    pub theme_index: usize,
+    pub lock_after: Option<Duration>,
+    pub locked: bool,
+    pub last_input: Instant,
+    pub clock: Option<DateTime<Local>>, // Waktu tetap saat merekam/replay log sesi; None = jam sistem
+    pub debug: DebugStats,
+    pub details: DetailsScroll,
+    pub hit_areas: HitAreas,
+    pub last_click: Option<(Instant, usize)>, // Waktu dan tugas yang terakhir diklik
+    pub history: History,
+    pub journal: Option<Journal>, // Catatan perubahan sejak simpan terakhir, untuk pemulihan crash
+    pub note_file: Option<NoteFile>, // Teks catatan yang sudah disimpan; None = semua catatan di memori
+    pub message: Option<String>,     // Hasil/error perintah `:`, hilang di tombol berikutnya
+    pub should_quit: bool,
+}
+
+impl Default for App {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl App {
+    pub fn new() -> Self {
+        Self::with_tabs(vec![
+            TabData::new(
+                "Work",
+                vec![
+                    Task::new("Finish project report"),
+                    Task::new("Email manager"),
+                ],
+            ),
+            TabData::new(
+                "Personal",
+                vec![Task::new("Buy groceries"), Task::new("Call family")],
+            ),
+            TabData::new(
+                "Hobbies",
+                vec![Task::new("Practice guitar"), Task::new("Read a book")],
+            ),
+        ])
+    }
+
+    pub fn with_tabs(tabs: Vec<TabData>) -> Self {
+        Self {
+            tabs,
+            active_tab: 0,
+            recent_tabs: vec![0],
+            input_mode: InputMode::Normal,
+            input: String::new(),
+            privacy_mode: false,
+            show_tags: false,
+            edit_in_place: false,
+            keymap: Keymap::default(),
+            pending_keys: Vec::new(),
+            animations: false,
+            animation: None,
+            themes: Theme::builtin(),
+            theme_index: 0,
+            lock_after: None,
+            locked: false,
+            last_input: Instant::now(),
+            clock: None,
+            debug: DebugStats::new(false),
+            details: DetailsScroll::default(),
+            hit_areas: HitAreas::default(),
+            last_click: None,
+            history: History::default(),
+            journal: None,
+            note_file: None,
+            message: None,
+            should_quit: false,
+        }
+    }
+
+    // Data kosong (tanpa tab) diganti data contoh supaya UI tetap bisa dipakai
+    pub fn from_database(database: storage::Database) -> Self {
+        if database.tabs.is_empty() {
+            return Self::new();
+        }
+        let tabs = database
+            .tabs
+            .into_iter()
+            .map(|record| {
+                let mut tab = TabData::new(record.name, record.tasks);
+                tab.statuses = record.statuses;
+                tab
+            })
+            .collect();
+        Self::with_tabs(tabs)
+    }
+
+    pub fn to_database(&self) -> storage::Database {
+        storage::Database {
+            tabs: self
+                .tabs
+                .iter()
+                .map(|tab| storage::TabRecord {
+                    name: tab.name.clone(),
+                    statuses: tab.statuses.clone(),
+                    tasks: tab.list.items.clone(),
+                })
+                .collect(),
+            notes_file: self.note_file.as_ref().map(NoteFile::file_name),
+        }
+    }
+
+    // Seperti `to_database`, tapi semua catatan ikut sebagai teks, untuk export dan
+    // log sesi yang harus bisa dibaca tanpa file catatan
+    pub fn to_database_with_notes(&self) -> io::Result<storage::Database> {
+        let mut database = self.to_database();
+        database.notes_file = None;
+        for task in database.tabs.iter_mut().flat_map(|tab| &mut tab.tasks) {
+            if task.notes.loaded().is_none() {
+                task.notes = Notes::text(self.note_text(&task.notes)?);
+            }
+        }
+        Ok(database)
+    }
+
+    // Teks catatan tugas; yang sudah disimpan dibaca dari file catatan
+    pub fn note_text(&self, notes: &Notes) -> io::Result<String> {
+        match (&self.note_file, notes.loaded()) {
+            (Some(file), _) => file.read(notes),
+            (None, Some(text)) => Ok(text.to_string()),
+            (None, None) => Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                "no notes file for stored notes",
+            )),
+        }
+    }
+
+    // Pindahkan catatan teks ke file catatan; dipanggil tepat sebelum file data
+    // disimpan (lihat NoteFile::store)
+    pub fn store_notes(&mut self) -> io::Result<()> {
+        match &mut self.note_file {
+            Some(file) => file.store(
+                self.tabs
+                    .iter_mut()
+                    .flat_map(|tab| tab.list.items.iter_mut())
+                    .map(|task| &mut task.notes),
+            ),
+            None => Ok(()),
+        }
+    }
+
+    pub fn current_tab(&self) -> &TabData {
+        &self.tabs[self.active_tab]
+    }
+
+    pub fn current_tab_mut(&mut self) -> &mut TabData {
+        &mut self.tabs[self.active_tab]
+    }
+
+    pub fn start_adding(&mut self) {
+        self.input.clear();
+        self.input_mode = InputMode::Adding;
+    }
+
+    pub fn start_editing(&mut self) {
+        let list = &self.current_tab().list;
+        if let Some(index) = list.state.selected() {
+            self.input = edit_text(&list.items[index]);
+            self.input_mode = InputMode::Editing(index);
+        }
+    }
+
+    pub fn start_editing_notes(&mut self) {
+        let list = &self.current_tab().list;
+        if let Some(index) = list.state.selected() {
+            match self.note_text(&list.items[index].notes) {
+                Ok(notes) => {
+                    self.input = notes;
+                    self.input_mode = InputMode::EditingNotes(index);
+                }
+                Err(err) => self.message = Some(format!("failed to read notes: {}", err)),
+            }
+        }
+    }
+
+    // Catatan yang isinya sama tidak dicatat sebagai perubahan, walau yang lama
+    // tersimpan di file dan yang baru masih teks
+    fn set_notes(&mut self, index: usize, notes: String) {
+        let Some(task) = self.current_tab().list.items.get(index) else {
+            return;
+        };
+        if self
+            .note_text(&task.notes)
+            .is_ok_and(|current| current == notes)
+        {
+            return;
+        }
+        self.update_task(index, |task, _| task.notes = Notes::text(notes));
+    }
+
+    // URL pertama di judul atau catatan tugas terpilih, untuk ditampilkan sebagai QR
+    pub fn selected_link(&self) -> Option<String> {
+        let task = self.selected_task()?;
+        let notes = self.note_text(&task.notes).unwrap_or_default();
+        task.title
+            .split_whitespace()
+            .chain(notes.split_whitespace())
+            .find(|word| word.starts_with("http://") || word.starts_with("https://"))
+            .map(str::to_string)
+    }
+
+    pub fn selected_task(&self) -> Option<&Task> {
+        let list = &self.current_tab().list;
+        list.state
+            .selected()
+            .and_then(|index| list.items.get(index))
+    }
+
+    pub fn toggle_selected(&mut self) {
+        self.update_selected(|task, statuses| {
+            if statuses.is_empty() {
+                task.done = !task.done;
+            } else {
+                // Selesai <-> status pertama
+                let target = if task.done { 0 } else { statuses.len() - 1 };
+                task.set_status(statuses, target);
+            }
+        });
+    }
+
+    // Pindahkan tugas terpilih ke status berikutnya/sebelumnya di tab ini
+    pub fn shift_status(&mut self, forward: bool) {
+        if self.current_tab().statuses.is_empty() {
+            return;
+        }
+        self.update_selected(|task, statuses| {
+            let current = task.status_index(statuses);
+            let target = if forward {
+                (current + 1).min(statuses.len() - 1)
+            } else {
+                current.saturating_sub(1)
+            };
+            task.set_status(statuses, target);
+        });
+    }
+
+    pub fn cycle_priority(&mut self, up: bool) {
+        self.update_selected(|task, _| task.priority = task.priority.cycle(up));
+    }
+
+    // Urutkan tab aktif dari prioritas tertinggi; urutan lama dipertahankan untuk yang setara
+    pub fn sort_by_priority(&mut self) {
+        let tab = self.active_tab;
+        let list = &self.current_tab().list;
+        let mut order: Vec<usize> = (0..list.items.len()).collect();
+        order.sort_by_key(|&index| std::cmp::Reverse(list.items[index].priority));
+        if order.iter().enumerate().any(|(new, &old)| new != old) {
+            self.apply_reorder(&order);
+            self.record(Action::Reorder { tab, order });
+        }
+    }
+
+    // Susun ulang daftar sesuai permutasi; seleksi ikut pindah bersama tugasnya
+    fn apply_reorder(&mut self, order: &[usize]) {
+        let list = &mut self.current_tab_mut().list;
+        if order.len() != list.items.len() {
+            return;
+        }
+        let mut old: Vec<Option<Task>> = mem::take(&mut list.items).into_iter().map(Some).collect();
+        list.items = order
+            .iter()
+            .filter_map(|&index| old.get_mut(index).and_then(Option::take))
+            .collect();
+        if let Some(selected) = list.state.selected() {
+            list.state
+                .select(order.iter().position(|&index| index == selected));
+        }
+    }
+
+    // Geser tugas terpilih satu posisi di antara tugas yang terlihat; tidak memutar di ujung
+    pub fn move_selected(&mut self, down: bool) {
+        let tab = self.active_tab;
+        let current = self.current_tab_mut();
+        let visible = current.visible_indices();
+        let Some(position) = current
+            .list
+            .state
+            .selected()
+            .and_then(|selected| visible.iter().position(|&index| index == selected))
+        else {
+            return;
+        };
+        let target = if down {
+            visible.get(position + 1)
+        } else {
+            position
+                .checked_sub(1)
+                .and_then(|position| visible.get(position))
+        };
+        if let Some(&to) = target {
+            let from = visible[position];
+            if current.list.swap(from, to) {
+                self.record(Action::Swap { tab, from, to });
+            }
+        }
+    }
+
+    pub fn delete_selected(&mut self) {
+        let tab = self.active_tab;
+        let list = &mut self.current_tab_mut().list;
+        if let Some(index) = list.state.selected() {
+            if let Some(task) = list.remove(index) {
+                self.record(Action::Delete { tab, index, task });
+            }
+        }
+    }
+
+    fn update_selected(&mut self, change: impl FnOnce(&mut Task, &[String])) {
+        if let Some(index) = self.current_tab().list.state.selected() {
+            self.update_task(index, change);
+        }
+    }
+
+    // Ubah satu tugas di tab aktif dan catat di riwayat kalau memang berubah
+    fn update_task(&mut self, index: usize, change: impl FnOnce(&mut Task, &[String])) {
+        let tab_index = self.active_tab;
+        let tab = self.current_tab_mut();
+        let Some(task) = tab.list.items.get_mut(index) else {
+            return;
+        };
+        let before = task.clone();
+        change(task, &tab.statuses);
+        if *task != before {
+            let after = task.clone();
+            self.record(Action::Update {
+                tab: tab_index,
+                index,
+                before,
+                after,
+            });
+        }
+    }
+
+    // Catat perubahan di riwayat undo dan di journal
+    fn record(&mut self, action: Action) {
+        self.log(Entry::Action(action.clone()));
+        self.history.record(action);
+    }
+
+    fn log(&mut self, entry: Entry) {
+        self.invalidate_caches();
+        if let Some(journal) = &mut self.journal {
+            if let Err(err) = journal.append(&entry) {
+                self.message = Some(format!("journal write failed: {}", err));
+            }
+        }
+    }
+
+    pub fn undo(&mut self) {
+        if let Some(action) = self.history.undo() {
+            self.log(Entry::Action(action.clone()));
+            self.apply(action);
+        }
+    }
+
+    pub fn redo(&mut self) {
+        if let Some(action) = self.history.redo() {
+            self.log(Entry::Action(action.clone()));
+            self.apply(action);
+        }
+    }
+
+    // Terapkan ulang isi journal setelah shutdown yang tidak normal.
+    // Dipanggil sebelum `journal` dipasang, jadi tidak tercatat dua kali.
+    pub fn replay(&mut self, entries: Vec<Entry>) {
+        for entry in entries {
+            match entry {
+                Entry::Action(action) => self.apply(action),
+                Entry::NewTab(name) => self.add_tab(name),
+                Entry::RenameTab { tab, name } => {
+                    if let Some(tab) = self.tabs.get_mut(tab) {
+                        tab.name = name;
+                    }
+                }
+                Entry::SetStatuses { tab, statuses } => {
+                    if let Some(tab) = self.tabs.get_mut(tab) {
+                        tab.statuses = statuses;
+                    }
+                }
+                Entry::DeleteTab(index) => {
+                    if index < self.tabs.len() {
+                        self.set_active_tab(index);
+                        self.delete_active_tab();
+                    }
+                }
+            }
+        }
+    }
+
+    // Semua perubahan tugas lewat `log` atau `apply`; hitungan tag dan hasil
+    // filter tiap tab dibuang
+    fn invalidate_caches(&mut self) {
+        for tab in &mut self.tabs {
+            tab.invalidate_caches();
+        }
+    }
+
+    // Terapkan aksi dari riwayat, lalu tampilkan tab dan tugas yang berubah
+    fn apply(&mut self, action: Action) {
+        self.invalidate_caches();
+        let tab = match &action {
+            Action::Add { tab, .. }
+            | Action::Delete { tab, .. }
+            | Action::Update { tab, .. }
+            | Action::Transfer { to_tab: tab, .. }
+            | Action::Swap { tab, .. }
+            | Action::Reorder { tab, .. } => *tab,
+        };
+        if tab >= self.tabs.len() {
+            return;
+        }
+        if let Action::Transfer {
+            from_tab,
+            from_index,
+            to_index,
+            task,
+            ..
+        } = action
+        {
+            self.transfer(from_tab, from_index, tab, to_index, task);
+            self.set_active_tab(tab);
+            let list = &mut self.current_tab_mut().list;
+            list.state
+                .select(Some(to_index.min(list.items.len().saturating_sub(1))));
+            return;
+        }
+        self.set_active_tab(tab);
+        if let Action::Reorder { order, .. } = &action {
+            self.apply_reorder(order);
+            return;
+        }
+        let list = &mut self.current_tab_mut().list;
+        match action {
+            Action::Add { index, task, .. } => {
+                let index = list.insert(index, task);
+                list.state.select(Some(index));
+            }
+            Action::Delete { index, .. } => {
+                list.remove(index);
+            }
+            Action::Update { index, after, .. } => {
+                if list.replace(index, after).is_some() {
+                    list.state.select(Some(index));
+                }
+            }
+            Action::Transfer { .. } => {}
+            Action::Swap { from, to, .. } => {
+                if list.swap(from, to) {
+                    list.state.select(Some(to));
+                }
+            }
+            Action::Reorder { .. } => {}
+        }
+    }
+
+    // Naik/turun di antara tugas yang terlihat (melewati yang tersaring)
+    pub fn select_next(&mut self, forward: bool) {
+        let tab = self.current_tab_mut();
+        if !tab.is_filtered() {
+            if forward {
+                tab.list.next();
+            } else {
+                tab.list.previous();
+            }
+            return;
+        }
+        let visible = tab.visible_indices();
+        if visible.is_empty() {
+            return;
+        }
+        let len = visible.len();
+        let position = tab
+            .list
+            .state
+            .selected()
+            .and_then(|selected| visible.iter().position(|&index| index == selected));
+        let next = match position {
+            Some(position) if forward => (position + 1) % len,
+            Some(position) => (position + len - 1) % len,
+            None => 0,
+        };
+        tab.list.state.select(Some(visible[next]));
+    }
+
+    // Lompat ke tugas pertama/terakhir yang terlihat
+    pub fn select_edge(&mut self, last: bool) {
+        let tab = self.current_tab_mut();
+        let visible = tab.visible_indices();
+        let edge = if last {
+            visible.last()
+        } else {
+            visible.first()
+        };
+        if let Some(&index) = edge {
+            tab.list.state.select(Some(index));
+        }
+    }
+
+    // Seleksi dan viewport bergeser satu layar, berhenti di ujung daftar
+    pub fn page_selection(&mut self, down: bool) {
+        let page = (self.hit_areas.list.height as usize).max(1);
+        let tab = self.current_tab_mut();
+        let visible = tab.visible_indices();
+        if visible.is_empty() {
+            return;
+        }
+        let last = visible.len() - 1;
+        let position = tab
+            .list
+            .state
+            .selected()
+            .and_then(|selected| visible.iter().position(|&index| index == selected));
+        let target = match position {
+            Some(position) if down => (position + page).min(last),
+            Some(position) => position.saturating_sub(page),
+            None => 0,
+        };
+        tab.list.state.select(Some(visible[target]));
+        // Posisi offset dihitung di daftar yang sedang tampil (hasil filter atau semua)
+        let view = if tab.is_filtered() {
+            &mut tab.filter_view
+        } else {
+            &mut tab.list.state
+        };
+        let offset = if down {
+            view.offset() + page
+        } else {
+            view.offset().saturating_sub(page)
+        };
+        *view.offset_mut() = offset.min(visible.len().saturating_sub(page));
+    }
+
+    pub fn start_command(&mut self) {
+        self.input.clear();
+        self.input_mode = InputMode::Command;
+    }
+
+    pub fn run_command(&mut self) {
+        let line = self.input.trim().to_string();
+        self.cancel_input();
+        if line.is_empty() {
+            return;
+        }
+        match command::parse(&line) {
+            Ok(command) => self.execute(command),
+            Err(err) => self.message = Some(err),
+        }
+    }
+
+    // Saran untuk isi baris perintah saat ini
+    pub fn command_completions(&self) -> Vec<String> {
+        let tab_names: Vec<String> = self.tabs.iter().map(|tab| tab.name.clone()).collect();
+        // Mode privasi: tag tidak ditawarkan karena diambil dari judul tugas
+        let tag_names: Vec<String> = if self.privacy_mode {
+            Vec::new()
+        } else {
+            let tab = self.current_tab();
+            match &tab.tag_cache {
+                Some(counts) => counts.iter().map(|(tag, _)| tag.clone()).collect(),
+                None => tab.count_tags().into_iter().map(|(tag, _)| tag).collect(),
+            }
+        };
+        let theme_names: Vec<&str> = self.themes.iter().map(|theme| theme.name).collect();
+        command::completions(&self.input, &tab_names, &tag_names, &theme_names)
+    }
+
+    // Tab di baris perintah: ambil saran pertama, lanjut mengetik argumen
+    pub fn complete_command(&mut self) {
+        if let Some(line) = self.command_completions().into_iter().next() {
+            let takes_argument = command::usage_for(&line)
+                .is_some_and(|usage| usage.contains(' ') && !line.contains(' '));
+            self.input = line;
+            if takes_argument {
+                self.input.push(' ');
+            }
+        }
+    }
+
+    fn execute(&mut self, command: Command) {
+        match command {
+            Command::Add(text) => self.add_task(&text),
+            Command::Edit(text) => {
+                if let Some(index) = self.current_tab().list.state.selected() {
+                    self.edit_task(index, &text);
+                }
+            }
+            Command::Notes(notes) => {
+                if let Some(index) = self.current_tab().list.state.selected() {
+                    self.set_notes(index, notes);
+                }
+            }
+            Command::Delete => self.delete_selected(),
+            Command::Done => self.toggle_selected(),
+            Command::Status(forward) => self.shift_status(forward),
+            Command::Priority(PriorityChange::Up) => self.cycle_priority(true),
+            Command::Priority(PriorityChange::Down) => self.cycle_priority(false),
+            Command::Priority(PriorityChange::Set(priority)) => {
+                self.update_selected(|task, _| task.priority = priority)
+            }
+            Command::Sort => self.sort_by_priority(),
+            Command::Move(down) => self.move_selected(down),
+            Command::Tag(tag) => self.update_selected(|task, _| toggle_tag(&mut task.title, &tag)),
+            Command::Tags(None) if self.show_tags => self.hide_tag_sidebar(),
+            Command::Tags(None) => self.show_tags = true,
+            Command::Tags(Some(tag)) => {
+                self.show_tags = true;
+                self.current_tab_mut().tag_filter = Some(tag);
+                self.keep_selection_visible();
+            }
+            Command::Filter(query) => {
+                self.current_tab_mut().filter = query;
+                self.keep_selection_visible();
+            }
+            Command::Search(query) => {
+                self.start_global_search();
+                self.input = query;
+            }
+            Command::Tab(name) => match self.find_tab(&name) {
+                Some(index) => self.set_active_tab(index),
+                None => self.message = Some(format!("no such tab: {}", name)),
+            },
+            Command::MoveTo(name) => self.send_selected_to_named_tab(&name, false),
+            Command::CopyTo(name) => self.send_selected_to_named_tab(&name, true),
+            Command::NewTab(name) => self.add_tab(name),
+            Command::RenameTab(name) => self.rename_tab(name),
+            Command::Statuses(statuses) => self.set_statuses(statuses),
+            Command::CloseTab => self.start_deleting_tab(),
+            Command::Undo => self.undo(),
+            Command::Redo => self.redo(),
+            Command::Details => self.toggle_details(),
+            Command::Privacy => self.privacy_mode = !self.privacy_mode,
+            Command::Debug => self.debug.visible = !self.debug.visible,
+            Command::Theme(None) => self.cycle_theme(),
+            Command::Theme(Some(name)) => {
+                match self.themes.iter().position(|theme| theme.name == name) {
+                    Some(index) => self.theme_index = index,
+                    None => self.message = Some(format!("unknown theme: {}", name)),
+                }
+            }
+            Command::Qr => self.show_qr(),
+            Command::Export { path, all } => self.export(&path, all),
+            Command::Import(path) => self.import(&path),
+            Command::Quit => self.should_quit = true,
+        }
+    }
+
+    fn send_selected_to_named_tab(&mut self, name: &str, copy: bool) {
+        match self.find_tab(name) {
+            Some(index) => self.send_selected_to_tab(index, copy),
+            None => self.message = Some(format!("no such tab: {}", name)),
+        }
+    }
+
+    // Nomor tab (mulai 1), nama persis, lalu awalan nama tanpa beda huruf besar/kecil
+    fn find_tab(&self, name: &str) -> Option<usize> {
+        if let Ok(number @ 1..) = name.parse::<usize>() {
+            return (number <= self.tabs.len()).then_some(number - 1);
+        }
+        let lower = name.to_lowercase();
+        self.tabs
+            .iter()
+            .position(|tab| tab.name == name)
+            .or_else(|| {
+                self.tabs
+                    .iter()
+                    .position(|tab| tab.name.to_lowercase().starts_with(&lower))
+            })
+    }
+
+    // Tulis semua tab (atau tab aktif saja) ke file lain; format mengikuti ekstensi
+    fn export(&mut self, path: &Path, all: bool) {
+        let mut tabs = match self.to_database_with_notes() {
+            Ok(database) => database.tabs,
+            Err(err) => {
+                self.message = Some(format!("export failed: {}", err));
+                return;
+            }
+        };
+        if !all {
+            tabs = vec![tabs.swap_remove(self.active_tab)];
+        }
+        self.message = Some(match export::write(path, tabs, self.now()) {
+            Ok(count) => format!("exported {} tasks to {}", count, path.display()),
+            Err(err) => format!("export failed: {}", err),
+        });
+    }
+
+    // Impor todo.txt ke tab baru, satu tab per tab:Nama (default: nama file)
+    fn import(&mut self, path: &Path) {
+        let text = match fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(err) => {
+                self.message = Some(format!("import failed: {}", err));
+                return;
+            }
+        };
+        let name = path
+            .file_stem()
+            .map_or("Imported".into(), |stem| stem.to_string_lossy());
+        let tabs = todotxt::parse(&text, &name);
+        let count: usize = tabs.iter().map(|tab| tab.tasks.len()).sum();
+        let first_tab = self.tabs.len();
+        for record in tabs {
+            self.add_tab(record.name);
+            let tab = self.active_tab;
+            // Hanya ke journal, bukan ke riwayat undo: tab baru juga tidak bisa di-undo
+            for (index, task) in record.tasks.into_iter().enumerate() {
+                self.log(Entry::Action(Action::Add {
+                    tab,
+                    index,
+                    task: task.clone(),
+                }));
+                self.current_tab_mut().list.items.push(task);
+            }
+        }
+        if first_tab < self.tabs.len() {
+            self.set_active_tab(first_tab);
+        }
+        self.message = Some(format!(
+            "imported {} tasks into {} tabs",
+            count,
+            self.tabs.len() - first_tab
+        ));
+    }
+
+    // Tag baru lewat kotak input; tag yang sudah ada dilepas
+    pub fn start_tagging(&mut self) {
+        if let Some(index) = self.current_tab().list.state.selected() {
+            self.input.clear();
+            self.input_mode = InputMode::Tagging(index);
+        }
+    }
+
+    // Buka sidebar tag (kalau belum) dan pindahkan fokus ke sana, kursor di tag aktif
+    pub fn focus_tag_sidebar(&mut self) {
+        self.show_tags = true;
+        let tab = self.current_tab_mut();
+        let filter = tab.tag_filter.clone();
+        let cursor = filter
+            .and_then(|tag| tab.tag_counts().iter().position(|(name, _)| *name == tag))
+            .map_or(0, |position| position + 1);
+        self.input_mode = InputMode::TagSidebar(cursor);
+    }
+
+    pub fn move_tag_cursor(&mut self, down: bool) {
+        if let InputMode::TagSidebar(cursor) = self.input_mode {
+            let len = self.current_tab_mut().tag_counts().len() + 1;
+            let cursor = if down {
+                (cursor + 1) % len
+            } else {
+                (cursor + len - 1) % len
+            };
+            self.input_mode = InputMode::TagSidebar(cursor);
+        }
+    }
+
+    // Enter di sidebar: saring dengan tag di kursor, lalu fokus kembali ke daftar
+    pub fn apply_tag_cursor(&mut self) {
+        if let InputMode::TagSidebar(cursor) = self.input_mode {
+            let tab = self.current_tab_mut();
+            let tag = cursor
+                .checked_sub(1)
+                .and_then(|index| tab.tag_counts().get(index))
+                .map(|(name, _)| name.clone());
+            tab.tag_filter = tag;
+        }
+        self.input_mode = InputMode::Normal;
+    }
+
+    // Tutup sidebar; saringan tag ikut dilepas supaya tidak ada filter tersembunyi
+    pub fn hide_tag_sidebar(&mut self) {
+        self.show_tags = false;
+        self.current_tab_mut().tag_filter = None;
+        self.input_mode = InputMode::Normal;
+    }
+
+    pub fn start_filtering(&mut self) {
+        self.input = self.current_tab().filter.clone();
+        self.input_mode = InputMode::Filtering;
+    }
+
+    // Dipanggil setiap input filter berubah supaya daftar langsung menyempit
+    pub fn update_filter(&mut self) {
+        if self.input_mode == InputMode::Filtering {
+            let query = self.input.clone();
+            self.current_tab_mut().filter = query;
+            self.keep_selection_visible();
+        }
+    }
+
+    pub fn clear_filter(&mut self) {
+        let tab = self.current_tab_mut();
+        tab.filter.clear();
+        tab.tag_filter = None;
+        self.cancel_input();
+    }
+
+    pub fn start_global_search(&mut self) {
+        self.input.clear();
+        self.input_mode = InputMode::GlobalSearch(0);
+        self.start_animation(Animation::PopupFade);
+    }
+
+    // Hasil pencarian di semua tab sebagai (index tab, index tugas)
+    pub fn global_matches(&self) -> Vec<(usize, usize)> {
+        if self.input.is_empty() {
+            return Vec::new();
+        }
+        self.tabs
+            .iter()
+            .enumerate()
+            .flat_map(|(tab_index, tab)| {
+                tab.list
+                    .items
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, task)| fuzzy_match(&task.title, &self.input))
+                    .map(move |(index, _)| (tab_index, index))
+            })
+            .collect()
+    }
+
+    pub fn move_search_cursor(&mut self, forward: bool) {
+        if let InputMode::GlobalSearch(cursor) = self.input_mode {
+            let len = self.global_matches().len();
+            if len == 0 {
+                return;
+            }
+            let cursor = if forward {
+                (cursor + 1) % len
+            } else {
+                (cursor + len - 1) % len
+            };
+            self.input_mode = InputMode::GlobalSearch(cursor);
+        }
+    }
+
+    // Query berubah, kursor kembali ke hasil pertama
+    pub fn reset_search_cursor(&mut self) {
+        if let InputMode::GlobalSearch(_) = self.input_mode {
+            self.input_mode = InputMode::GlobalSearch(0);
+        }
+    }
+
+    // Pindah ke tab hasil pencarian dan pilih tugasnya
+    pub fn jump_to_search_result(&mut self) {
+        if let InputMode::GlobalSearch(cursor) = self.input_mode {
+            if let Some(&(tab, index)) = self.global_matches().get(cursor) {
+                self.set_active_tab(tab);
+                let tab = self.current_tab_mut();
+                // Filter tab tujuan bisa menyembunyikan tugas itu
+                if !tab.visible_indices().contains(&index) {
+                    tab.filter.clear();
+                    tab.tag_filter = None;
+                }
+                tab.list.state.select(Some(index));
+            }
+        }
+        self.cancel_input();
+    }
+
+    // Jangan biarkan seleksi menunjuk tugas yang sedang tersembunyi
+    pub fn keep_selection_visible(&mut self) {
+        let tab = self.current_tab_mut();
+        if !tab.is_filtered() {
+            return;
+        }
+        let visible = tab.visible_indices();
+        let selected = tab.list.state.selected();
+        if !selected.is_some_and(|selected| visible.contains(&selected)) {
+            tab.list.state.select(visible.first().copied());
+        }
+    }
+
+    pub fn show_qr(&mut self) {
+        if self.selected_task().is_some() {
+            self.input_mode = InputMode::ShowingQr;
+            self.start_animation(Animation::PopupFade);
+        }
+    }
+
+    // Klik judul tab untuk pindah tab, klik baris untuk memilih tugas;
+    // klik kedua di baris yang sama membuka/menutup panel detail
+    pub fn click(&mut self, column: u16, row: u16) {
+        let hit = |area: &Rect| {
+            column >= area.x && column < area.right() && row >= area.y && row < area.bottom()
+        };
+        if let Some(index) = self.hit_areas.tabs.iter().position(hit) {
+            self.select_tab(index);
+            return;
+        }
+        if !hit(&self.hit_areas.list) {
+            return;
+        }
+        let position = self.hit_areas.list_offset + (row - self.hit_areas.list.y) as usize;
+        let Some(&index) = self.current_tab().visible_indices().get(position) else {
+            return;
+        };
+        self.current_tab_mut().list.state.select(Some(index));
+        match self.last_click {
+            Some((at, last)) if last == index && at.elapsed() < DOUBLE_CLICK => {
+                self.toggle_details();
+                self.last_click = None;
+            }
+            _ => self.last_click = Some((Instant::now(), index)),
+        }
+    }
+
+    // Roda mouse di atas panel detail menggulir detail, di tempat lain memindah seleksi
+    pub fn scroll_at(&mut self, down: bool, column: u16, row: u16) {
+        let over_details = self.hit_areas.details.is_some_and(|area| {
+            column >= area.x && column < area.right() && row >= area.y && row < area.bottom()
+        });
+        if over_details {
+            self.scroll_details(down, false);
+        } else {
+            self.select_next(down);
+        }
+    }
+
+    pub fn show_help(&mut self) {
+        self.input_mode = InputMode::ShowingHelp(0);
+        self.start_animation(Animation::PopupFade);
+    }
+
+    // Batas bawah scroll dijaga saat render
+    pub fn scroll_help(&mut self, down: bool) {
+        if let InputMode::ShowingHelp(offset) = self.input_mode {
+            let offset = if down {
+                offset.saturating_add(1)
+            } else {
+                offset.saturating_sub(1)
+            };
+            self.input_mode = InputMode::ShowingHelp(offset);
+        }
+    }
+
+    // Scroll per baris atau per halaman; batas bawah dijaga saat render
+    pub fn scroll_details(&mut self, down: bool, page: bool) {
+        if !self.current_tab().show_details {
+            return;
+        }
+        let step = if page {
+            self.details.page.saturating_sub(1).max(1)
+        } else {
+            1
+        };
+        self.details.offset = if down {
+            self.details.offset.saturating_add(step)
+        } else {
+            self.details.offset.saturating_sub(step)
+        };
+    }
+
+    fn start_animation(&mut self, animation: Animation) {
+        if self.animations {
+            self.animation = Some((animation, Instant::now()));
+        }
+    }
+
+    // Animasi yang sedang berjalan beserta progresnya (0.0 - 1.0)
+    pub fn animation_progress(&self) -> Option<(Animation, f32)> {
+        let (animation, started) = self.animation?;
+        let progress = started.elapsed().as_secs_f32() / ANIMATION_DURATION.as_secs_f32();
+        (progress < 1.0).then_some((animation, progress))
+    }
+
+    pub fn theme(&self) -> &Theme {
+        &self.themes[self.theme_index]
+    }
+
+    // Pakai tema dari config; tema bawaan dengan nama sama diganti
+    pub fn use_theme(&mut self, theme: Theme) {
+        match self.themes.iter().position(|t| t.name == theme.name) {
+            Some(index) => {
+                self.themes[index] = theme;
+                self.theme_index = index;
+            }
+            None => {
+                self.themes.push(theme);
+                self.theme_index = self.themes.len() - 1;
+            }
+        }
+    }
+
+    pub fn cycle_theme(&mut self) {
+        self.theme_index = (self.theme_index + 1) % self.themes.len();
+    }
+
+    pub fn toggle_details(&mut self) {
+        let tab = self.current_tab_mut();
+        tab.show_details = !tab.show_details;
+    }
+
+    pub fn start_new_tab(&mut self) {
+        self.input.clear();
+        self.input_mode = InputMode::NewTab;
+    }
+
+    pub fn start_renaming_tab(&mut self) {
+        self.input = self.current_tab().name.clone();
+        self.input_mode = InputMode::RenamingTab;
+    }
+
+    // Tab terakhir tidak bisa dihapus
+    pub fn start_deleting_tab(&mut self) {
+        if self.tabs.len() > 1 {
+            self.input_mode = InputMode::ConfirmDeleteTab;
+        }
+    }
+
+    pub fn delete_active_tab(&mut self) {
+        if self.tabs.len() > 1 {
+            let removed = self.active_tab;
+            self.log(Entry::DeleteTab(removed));
+            self.tabs.remove(removed);
+            // Index tab di riwayat undo tidak berlaku lagi
+            self.history.clear();
+            // Geser index riwayat supaya tetap menunjuk tab yang sama
+            self.recent_tabs.retain(|&index| index != removed);
+            for index in self.recent_tabs.iter_mut() {
+                if *index > removed {
+                    *index -= 1;
+                }
+            }
+            let next = self
+                .recent_tabs
+                .first()
+                .copied()
+                .unwrap_or(removed.min(self.tabs.len() - 1));
+            self.set_active_tab(next);
+        }
+        self.input_mode = InputMode::Normal;
+    }
+
+    // Semua tab urut dari yang terakhir dipakai; tab yang belum pernah dibuka di belakang
+    pub fn tabs_by_recency(&self) -> Vec<usize> {
+        let mut order = self.recent_tabs.clone();
+        order.extend((0..self.tabs.len()).filter(|index| !self.recent_tabs.contains(index)));
+        order
+    }
+
+    // Kursor awal di tab sebelumnya, jadi switcher + Enter bolak-balik dua tab
+    pub fn start_switching_tab(&mut self) {
+        let cursor = if self.tabs.len() > 1 { 1 } else { 0 };
+        self.input_mode = InputMode::SwitchingTab(cursor);
+        self.start_animation(Animation::PopupFade);
+    }
+
+    pub fn move_switcher_cursor(&mut self, forward: bool) {
+        if let InputMode::SwitchingTab(cursor) = self.input_mode {
+            let len = self.tabs.len();
+            let cursor = if forward {
+                (cursor + 1) % len
+            } else {
+                (cursor + len - 1) % len
+            };
+            self.input_mode = InputMode::SwitchingTab(cursor);
+        }
+    }
+
+    pub fn confirm_switch_tab(&mut self) {
+        if let InputMode::SwitchingTab(cursor) = self.input_mode {
+            if let Some(&index) = self.tabs_by_recency().get(cursor) {
+                self.set_active_tab(index);
+            }
+        }
+        self.input_mode = InputMode::Normal;
+    }
+
+    // Popup pilih tab tujuan; kursor mulai di tab pertama selain tab aktif
+    pub fn start_picking_tab(&mut self, copy: bool) {
+        if self.selected_task().is_none() || (!copy && self.tabs.len() < 2) {
+            return;
+        }
+        let cursor = if self.active_tab == 0 && self.tabs.len() > 1 {
+            1
+        } else {
+            0
+        };
+        self.input_mode = InputMode::PickingTab { copy, cursor };
+        self.start_animation(Animation::PopupFade);
+    }
+
+    pub fn move_picker_cursor(&mut self, forward: bool) {
+        if let InputMode::PickingTab { copy, cursor } = self.input_mode {
+            let len = self.tabs.len();
+            let cursor = if forward {
+                (cursor + 1) % len
+            } else {
+                (cursor + len - 1) % len
+            };
+            self.input_mode = InputMode::PickingTab { copy, cursor };
+        }
+    }
+
+    // Enter di popup, atau angka 1-9 langsung memilih tab ke-N
+    pub fn confirm_pick_tab(&mut self, index: Option<usize>) {
+        if let InputMode::PickingTab { copy, cursor } = self.input_mode {
+            let target = index.unwrap_or(cursor);
+            if target < self.tabs.len() {
+                self.send_selected_to_tab(target, copy);
+            }
+        }
+        self.input_mode = InputMode::Normal;
+    }
+
+    // Tugas ditaruh di akhir tab tujuan dengan semua datanya; tab aktif tidak berubah
+    pub fn send_selected_to_tab(&mut self, target: usize, copy: bool) {
+        let from_tab = self.active_tab;
+        let Some(from_index) = self.current_tab().list.state.selected() else {
+            return;
+        };
+        if target >= self.tabs.len() || (!copy && target == from_tab) {
+            return;
+        }
+        let task = self.current_tab().list.items[from_index].clone();
+        let to_index = self.tabs[target].list.items.len();
+        let name = self.tabs[target].name.clone();
+        if copy {
+            self.tabs[target].list.items.push(task.clone());
+            self.record(Action::Add {
+                tab: target,
+                index: to_index,
+                task,
+            });
+            self.message = Some(format!("Copied to {}", name));
+        } else {
+            self.transfer(from_tab, from_index, target, to_index, task.clone());
+            self.record(Action::Transfer {
+                from_tab,
+                from_index,
+                to_tab: target,
+                to_index,
+                task,
+            });
+            self.message = Some(format!("Moved to {}", name));
+        }
+    }
+
+    // Pindahkan tugas antar tab tanpa mencatat riwayat (dipakai juga oleh undo/redo)
+    fn transfer(
+        &mut self,
+        from_tab: usize,
+        from_index: usize,
+        to_tab: usize,
+        to_index: usize,
+        task: Task,
+    ) {
+        if from_tab >= self.tabs.len() || to_tab >= self.tabs.len() {
+            return;
+        }
+        if self.tabs[from_tab].list.remove(from_index).is_none() {
+            return;
+        }
+        self.tabs[to_tab].list.insert(to_index, task);
+    }
+
+    pub fn cancel_input(&mut self) {
+        self.input.clear();
+        self.input_mode = InputMode::Normal;
+    }
+
+    // Simpan isi input sesuai mode: tugas/tab baru, atau ganti judul/catatan/nama tab
+    pub fn submit_input(&mut self) {
+        if let InputMode::EditingNotes(index) = self.input_mode {
+            // Catatan boleh dikosongkan
+            let notes = self.input.trim().to_string();
+            self.set_notes(index, notes);
+            self.cancel_input();
+            return;
+        }
+        let text = self.input.trim().to_string();
+        if !text.is_empty() {
+            match self.input_mode {
+                InputMode::Adding => self.add_task(&text),
+                InputMode::Editing(index) => self.edit_task(index, &text),
+                InputMode::Tagging(index) => {
+                    self.update_task(index, |task, _| toggle_tag(&mut task.title, &text))
+                }
+                InputMode::NewTab => self.add_tab(text),
+                InputMode::RenamingTab => self.rename_tab(text),
+                InputMode::Normal
+                | InputMode::EditingNotes(_)
+                | InputMode::Filtering
+                | InputMode::GlobalSearch(_)
+                | InputMode::Command
+                | InputMode::ShowingQr
+                | InputMode::ShowingHelp(_)
+                | InputMode::ConfirmDeleteTab
+                | InputMode::SwitchingTab(_)
+                | InputMode::PickingTab { .. }
+                | InputMode::TagSidebar(_) => {}
+            }
+        }
+        self.cancel_input();
+    }
+
+    // "beli susu tomorrow" -> judul "beli susu", jatuh tempo besok
+    fn add_task(&mut self, text: &str) {
+        let tab = self.active_tab;
+        let now = self.now();
+        let (title, due) = due::parse_quick_entry(text, now.date_naive());
+        let task = Task {
+            due,
+            created: Some(now),
+            ..Task::new(title)
+        };
+        let list = &mut self.current_tab_mut().list;
+        list.items.push(task.clone());
+        let index = list.items.len() - 1;
+        list.state.select(Some(index));
+        self.record(Action::Add { tab, index, task });
+    }
+
+    // Sama seperti quick entry: tanggal di akhir teks jadi jatuh tempo, dan tanpa
+    // tanggal jatuh temponya dihapus. Teks yang tidak diubah dibiarkan, supaya judul
+    // yang kebetulan diakhiri "friday" tidak berubah jadi jatuh tempo.
+    fn edit_task(&mut self, index: usize, text: &str) {
+        let (title, due) = due::parse_quick_entry(text, self.now().date_naive());
+        self.update_task(index, |task, _| {
+            if edit_text(task) != text {
+                task.title = title;
+                task.due = due;
+            }
+        });
+    }
+
+    fn add_tab(&mut self, name: String) {
+        self.log(Entry::NewTab(name.clone()));
+        self.tabs.push(TabData::new(name, Vec::new()));
+        self.set_active_tab(self.tabs.len() - 1);
+    }
+
+    fn rename_tab(&mut self, name: String) {
+        let tab = self.active_tab;
+        self.log(Entry::RenameTab {
+            tab,
+            name: name.clone(),
+        });
+        self.current_tab_mut().name = name;
+    }
+
+    // Status tugas yang tidak ada di daftar baru dianggap status pertama (atau
+    // terakhir kalau sudah selesai), jadi tugasnya sendiri tidak perlu diubah
+    fn set_statuses(&mut self, statuses: Vec<String>) {
+        let tab = self.active_tab;
+        self.log(Entry::SetStatuses {
+            tab,
+            statuses: statuses.clone(),
+        });
+        self.current_tab_mut().statuses = statuses;
+    }
+
+    pub fn select_tab(&mut self, index: usize) {
+        if index < self.tabs.len() {
+            self.set_active_tab(index);
+        }
+    }
+
+    // Pindah ke tab berikutnya/sebelumnya, memutar di ujung
+    pub fn cycle_tab(&mut self, forward: bool) {
+        let len = self.tabs.len();
+        let index = if forward {
+            (self.active_tab + 1) % len
+        } else {
+            (self.active_tab + len - 1) % len
+        };
+        self.set_active_tab(index);
+    }
+
+    fn set_active_tab(&mut self, index: usize) {
+        if index != self.active_tab {
+            self.start_animation(Animation::TabSlide);
+        }
+        self.active_tab = index;
+        self.recent_tabs.retain(|&recent| recent != index);
+        self.recent_tabs.insert(0, index);
+    }
+
+    // Dipanggil setiap tick dari loop event
+    pub fn on_tick(&mut self) {
+        if self.idle_timeout_reached() {
+            self.locked = true;
+        }
+    }
+
+    pub fn idle_timeout_reached(&self) -> bool {
+        match self.lock_after {
+            Some(timeout) => !self.locked && self.last_input.elapsed() >= timeout,
+            None => false,
+        }
+    }
+
+    pub fn on_resize(&mut self, area: Rect) {
+        // Baris yang terlihat = tinggi bagian dalam panel Tasks
+        let tasks_area = ui::main_chunks(area)[1];
+        let visible_rows = self.theme().block(Pane::Tasks).inner(tasks_area).height as usize;
+        for tab in self.tabs.iter_mut() {
+            tab.list.clamp(visible_rows);
+        }
+    }
+
+    pub fn now(&self) -> DateTime<Local> {
+        self.clock.unwrap_or_else(Local::now)
+    }
+
+    pub fn task_count(&self) -> usize {
+        self.tabs.iter().map(|tab| tab.list.items.len()).sum()
+    }
+
+    // Perkiraan pemakaian memori untuk overlay debug
+    pub fn memory_report(&self) -> MemoryReport {
+        let tasks = self.tabs.iter().flat_map(|tab| &tab.list.items);
+        MemoryReport {
+            structs: self.tabs.len() * mem::size_of::<TabData>()
+                + self
+                    .tabs
+                    .iter()
+                    .map(|tab| tab.list.items.capacity() * mem::size_of::<Task>())
+                    .sum::<usize>(),
+            text: self
+                .tabs
+                .iter()
+                .map(|tab| tab.name.capacity())
+                .sum::<usize>()
+                + tasks.map(Task::heap_size).sum::<usize>(),
+            history: self.history.memory_estimate(),
+        }
+    }
+
+    // Isi semua tab dengan tugas sintetis untuk uji performa
+    pub fn with_bench_data(count: usize) -> Self {
+        let mut app = Self::new();
+        let tab_count = app.tabs.len();
+        for tab in app.tabs.iter_mut() {
+            tab.list.items.clear();
+        }
+        for i in 0..count {
+            let tab = &mut app.tabs[i % tab_count];
+            let title = format!("Synthetic task {} ({})", i + 1, tab.name);
+            tab.list.items.push(Task::new(title));
+        }
+        app
+    }
+}
@@ -0,0 +1,253 @@
+use crate::app::Priority;
+use std::path::PathBuf;
+
+// Perintah yang bisa diketik setelah `:`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Command {
+    Add(String),
+    Edit(String),
+    Notes(String),
+    Delete,
+    Done,
+    Status(bool),          // true = status berikutnya
+    Statuses(Vec<String>), // Kosong = cukup selesai/belum
+    Priority(PriorityChange),
+    Sort,
+    Move(bool), // true = ke bawah
+    MoveTo(String),
+    CopyTo(String),
+    Tag(String),
+    Tags(Option<String>), // Tanpa nama = buka/tutup sidebar tag
+    Filter(String),       // Kosong = hapus filter
+    Search(String),
+    Tab(String),
+    NewTab(String),
+    RenameTab(String),
+    CloseTab,
+    Undo,
+    Redo,
+    Details,
+    Privacy,
+    Debug,
+    Theme(Option<String>), // Tanpa nama = tema berikutnya
+    Qr,
+    Export { path: PathBuf, all: bool }, // all = false: hanya tab aktif
+    Import(PathBuf),
+    Quit,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PriorityChange {
+    Up,
+    Down,
+    Set(Priority),
+}
+
+// Nama perintah dan cara pakainya, juga dipakai untuk saran
+pub const COMMANDS: [(&str, &str); 30] = [
+    ("add", "add <title> [today|tomorrow|mon..sun|YYYY-MM-DD]"),
+    ("edit", "edit <title> [today|tomorrow|mon..sun|YYYY-MM-DD]"),
+    ("notes", "notes <text>"),
+    ("delete", "delete"),
+    ("done", "done"),
+    ("status", "status next|prev"),
+    ("statuses", "statuses [name,name,...]"),
+    ("priority", "priority up|down|high|medium|low|none"),
+    ("sort", "sort priority"),
+    ("move", "move up|down"),
+    ("moveto", "moveto <tab name|number>"),
+    ("copyto", "copyto <tab name|number>"),
+    ("tag", "tag <name>"),
+    ("tags", "tags [name]"),
+    ("filter", "filter [query]"),
+    ("search", "search <query>"),
+    ("tab", "tab <name|number>"),
+    ("tabnew", "tabnew <name>"),
+    ("tabrename", "tabrename <name>"),
+    ("tabclose", "tabclose"),
+    ("undo", "undo"),
+    ("redo", "redo"),
+    ("details", "details"),
+    ("privacy", "privacy"),
+    ("debug", "debug"),
+    ("theme", "theme [name]"),
+    ("qr", "qr"),
+    ("export", "export [tab] <file.json|.md|.txt|.ics>"),
+    ("import", "import <todo.txt>"),
+    ("quit", "quit"),
+];
+
+// Pilihan argumen tetap untuk saran
+const STATUS_ARGS: [&str; 2] = ["next", "prev"];
+const PRIORITY_ARGS: [&str; 6] = ["up", "down", "high", "medium", "low", "none"];
+const SORT_ARGS: [&str; 1] = ["priority"];
+const MOVE_ARGS: [&str; 2] = ["up", "down"];
+
+pub fn parse(input: &str) -> Result<Command, String> {
+    let input = input.trim();
+    let (word, arg) = match input.split_once(' ') {
+        Some((word, arg)) => (word, arg.trim()),
+        None => (input, ""),
+    };
+    let name = resolve_name(word)?;
+    let required = |what: &str| {
+        if arg.is_empty() {
+            Err(format!("usage: {}", usage(name).unwrap_or(what)))
+        } else {
+            Ok(arg.to_string())
+        }
+    };
+    let command = match name {
+        "add" => Command::Add(required("add <title>")?),
+        "edit" => Command::Edit(required("edit <title>")?),
+        "notes" => Command::Notes(arg.to_string()),
+        "delete" => Command::Delete,
+        "done" => Command::Done,
+        "status" => match arg {
+            "next" | "" => Command::Status(true),
+            "prev" => Command::Status(false),
+            _ => return Err(format!("usage: {}", usage(name).unwrap_or_default())),
+        },
+        "statuses" => {
+            let names: Vec<String> = arg
+                .split(',')
+                .map(str::trim)
+                .filter(|name| !name.is_empty())
+                .map(str::to_string)
+                .collect();
+            // Status terakhir berarti selesai, jadi satu status saja tidak masuk akal;
+            // nama yang sama dua kali tidak bisa dibedakan
+            let repeated = (1..names.len()).any(|index| names[..index].contains(&names[index]));
+            if names.len() == 1 || repeated {
+                return Err("statuses: need at least two different names".to_string());
+            }
+            Command::Statuses(names)
+        }
+        "priority" => Command::Priority(match arg {
+            "up" | "" => PriorityChange::Up,
+            "down" => PriorityChange::Down,
+            "high" => PriorityChange::Set(Priority::High),
+            "medium" => PriorityChange::Set(Priority::Medium),
+            "low" => PriorityChange::Set(Priority::Low),
+            "none" => PriorityChange::Set(Priority::None),
+            _ => return Err(format!("usage: {}", usage(name).unwrap_or_default())),
+        }),
+        "sort" => match arg {
+            "priority" | "" => Command::Sort,
+            _ => return Err(format!("usage: {}", usage(name).unwrap_or_default())),
+        },
+        "move" => match arg {
+            "up" => Command::Move(false),
+            "down" => Command::Move(true),
+            _ => return Err(format!("usage: {}", usage(name).unwrap_or_default())),
+        },
+        "moveto" => Command::MoveTo(required("moveto <tab>")?),
+        "copyto" => Command::CopyTo(required("copyto <tab>")?),
+        "tag" => Command::Tag(required("tag <name>")?),
+        "tags" => {
+            Command::Tags((!arg.is_empty()).then(|| arg.trim_start_matches('#').to_lowercase()))
+        }
+        "filter" => Command::Filter(arg.to_string()),
+        "search" => Command::Search(arg.to_string()),
+        "tab" => Command::Tab(required("tab <name>")?),
+        "tabnew" => Command::NewTab(required("tabnew <name>")?),
+        "tabrename" => Command::RenameTab(required("tabrename <name>")?),
+        "tabclose" => Command::CloseTab,
+        "undo" => Command::Undo,
+        "redo" => Command::Redo,
+        "details" => Command::Details,
+        "privacy" => Command::Privacy,
+        "debug" => Command::Debug,
+        "theme" => Command::Theme((!arg.is_empty()).then(|| arg.to_string())),
+        "qr" => Command::Qr,
+        "export" => {
+            let arg = required("export <path>")?;
+            match arg.strip_prefix("tab ") {
+                Some(path) => Command::Export {
+                    path: PathBuf::from(path.trim()),
+                    all: false,
+                },
+                None => Command::Export {
+                    path: PathBuf::from(arg),
+                    all: true,
+                },
+            }
+        }
+        "import" => Command::Import(PathBuf::from(required("import <path>")?)),
+        "quit" => Command::Quit,
+        _ => return Err(format!("unknown command: {}", word)),
+    };
+    Ok(command)
+}
+
+// Nama lengkap, alias `q`, atau awalan yang hanya cocok dengan satu perintah
+fn resolve_name(word: &str) -> Result<&'static str, String> {
+    if word == "q" {
+        return Ok("quit");
+    }
+    if let Some((name, _)) = COMMANDS.iter().find(|(name, _)| *name == word) {
+        return Ok(name);
+    }
+    let matches: Vec<&str> = COMMANDS
+        .iter()
+        .map(|(name, _)| *name)
+        .filter(|name| name.starts_with(word))
+        .collect();
+    match matches.as_slice() {
+        [name] => Ok(name),
+        [] => Err(format!("unknown command: {}", word)),
+        _ => Err(format!(
+            "ambiguous command: {} ({})",
+            word,
+            matches.join(", ")
+        )),
+    }
+}
+
+fn usage(name: &str) -> Option<&'static str> {
+    COMMANDS
+        .iter()
+        .find(|(command, _)| *command == name)
+        .map(|(_, usage)| *usage)
+}
+
+// Saran untuk isi baris perintah: nama perintah dulu, lalu argumennya.
+// Setiap saran adalah isi baris lengkap, siap menggantikan input.
+pub fn completions(
+    input: &str,
+    tab_names: &[String],
+    tag_names: &[String],
+    theme_names: &[&str],
+) -> Vec<String> {
+    let Some((word, arg)) = input.split_once(' ') else {
+        return COMMANDS
+            .iter()
+            .filter(|(name, _)| name.starts_with(input))
+            .map(|(name, _)| name.to_string())
+            .collect();
+    };
+    let Ok(name) = resolve_name(word) else {
+        return Vec::new();
+    };
+    let options: Vec<&str> = match name {
+        "tab" | "moveto" | "copyto" => tab_names.iter().map(String::as_str).collect(),
+        "tag" | "tags" => tag_names.iter().map(String::as_str).collect(),
+        "theme" => theme_names.to_vec(),
+        "status" => STATUS_ARGS.to_vec(),
+        "priority" => PRIORITY_ARGS.to_vec(),
+        "sort" => SORT_ARGS.to_vec(),
+        "move" => MOVE_ARGS.to_vec(),
+        _ => Vec::new(),
+    };
+    let arg = arg.trim_start().to_lowercase();
+    options
+        .into_iter()
+        .filter(|option| option.to_lowercase().starts_with(&arg))
+        .map(|option| format!("{} {}", name, option))
+        .collect()
+}
+
+// Cara pakai untuk ditampilkan di samping saran nama perintah
+pub fn usage_for(line: &str) -> Option<&'static str> {
+    usage(line.split(' ').next().unwrap_or(line))
+}
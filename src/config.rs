@@ -0,0 +1,58 @@
+use crate::{keymap::KeysConfig, theme::ThemeConfig};
+use serde::Deserialize;
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+// Isi config.toml; bagian yang tidak ditulis memakai bawaan
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct Config {
+    pub keys: KeysConfig,
+    pub theme: ThemeConfig,
+    // Lapisan tombol ala vim (j/k, gg/G, dd, x, i/a, :) di atas [keys]
+    pub vim: bool,
+    // Transisi singkat saat ganti tab dan membuka popup
+    pub animations: bool,
+    // `e` mengedit judul langsung di baris daftar; --edit-in-place juga menyalakannya
+    pub edit_in_place: bool,
+    // Kunci layar setelah sekian detik tanpa input; 0 atau tidak ada = tidak pernah.
+    // --lock-after menimpa nilai ini.
+    pub lock_after: Option<u64>,
+}
+
+impl Config {
+    pub fn lock_after(&self) -> Option<Duration> {
+        lock_timeout(self.lock_after)
+    }
+}
+
+// Detik dari config atau --lock-after; 0 berarti kunci dimatikan
+pub fn lock_timeout(secs: Option<u64>) -> Option<Duration> {
+    secs.filter(|&secs| secs > 0).map(Duration::from_secs)
+}
+
+// ~/.config/firsttui/config.toml di Linux, lokasi setara di OS lain
+pub fn default_path() -> Option<PathBuf> {
+    dirs::config_dir().map(|dir| dir.join("firsttui").join("config.toml"))
+}
+
+// Ok(None) kalau file config tidak ada
+pub fn load(path: &Path) -> io::Result<Option<Config>> {
+    read(path)?.map(|contents| parse(&contents)).transpose()
+}
+
+// Isi mentah config.toml; log sesi menyimpannya apa adanya
+pub fn read(path: &Path) -> io::Result<Option<String>> {
+    match fs::read_to_string(path) {
+        Ok(contents) => Ok(Some(contents)),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(err) => Err(err),
+    }
+}
+
+pub fn parse(contents: &str) -> io::Result<Config> {
+    toml::from_str(contents).map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+}
@@ -0,0 +1,46 @@
+use chrono::{Datelike, Duration, NaiveDate, Weekday};
+
+// Pisahkan tanggal jatuh tempo di akhir input, misalnya "buy milk tomorrow" atau
+// "report fri". Input tanpa kata tanggal dikembalikan apa adanya.
+pub fn parse_quick_entry(input: &str, today: NaiveDate) -> (String, Option<NaiveDate>) {
+    let input = input.trim();
+    if let Some((title, word)) = input.rsplit_once(' ') {
+        let title = title.trim_end();
+        if let Some(due) = parse_date_word(word, today) {
+            if !title.is_empty() {
+                return (title.to_string(), Some(due));
+            }
+        }
+    }
+    (input.to_string(), None)
+}
+
+fn parse_date_word(word: &str, today: NaiveDate) -> Option<NaiveDate> {
+    let word = word.to_lowercase();
+    match word.as_str() {
+        "today" => return Some(today),
+        "tomorrow" | "tmr" => return Some(today + Duration::days(1)),
+        _ => {}
+    }
+    if let Some(weekday) = parse_weekday(&word) {
+        // Hari yang sama berarti hari ini, selain itu hari itu berikutnya
+        let ahead =
+            (weekday.num_days_from_monday() + 7 - today.weekday().num_days_from_monday()) % 7;
+        return Some(today + Duration::days(ahead as i64));
+    }
+    NaiveDate::parse_from_str(&word, "%Y-%m-%d").ok()
+}
+
+fn parse_weekday(word: &str) -> Option<Weekday> {
+    let weekday = match word {
+        "mon" | "monday" => Weekday::Mon,
+        "tue" | "tues" | "tuesday" => Weekday::Tue,
+        "wed" | "wednesday" => Weekday::Wed,
+        "thu" | "thurs" | "thursday" => Weekday::Thu,
+        "fri" | "friday" => Weekday::Fri,
+        "sat" | "saturday" => Weekday::Sat,
+        "sun" | "sunday" => Weekday::Sun,
+        _ => return None,
+    };
+    Some(weekday)
+}
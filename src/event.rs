@@ -0,0 +1,308 @@
+use crate::{
+    app::{App, InputMode},
+    keymap::Action,
+    session::{self, Record},
+};
+use chrono::{DateTime, Local};
+use crossterm::event::{
+    self, Event, KeyCode, KeyEvent, KeyEventKind, MouseButton, MouseEvent, MouseEventKind,
+};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::VecDeque,
+    io,
+    time::{Duration, Instant},
+};
+
+// Event yang diproses loop utama; Tick dikirim secara berkala walau tanpa input
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AppEvent {
+    Key(KeyEvent),
+    Mouse(MouseEvent),
+    Resize(u16, u16),
+    Tick,
+}
+
+// Menggabungkan input terminal dengan tick berkala memakai poll + timeout
+pub struct Events {
+    tick_rate: Duration,
+    last_tick: Instant,
+    // Input dari log sesi (--replay), dikirim pada jarak waktu yang sama seperti saat direkam
+    replay: VecDeque<Record>,
+    replay_started: Instant,
+    replay_epoch: DateTime<Local>,
+    replay_clock: Option<DateTime<Local>>,
+}
+
+impl Events {
+    pub fn new(tick_rate: Duration) -> Self {
+        Self::replay(tick_rate, Local::now(), Vec::new())
+    }
+
+    // Selama masih ada rekaman, input terminal dibuang supaya tidak ikut mengubah hasil.
+    // `started` adalah waktu mulai sesi yang direkam (Header::started).
+    pub fn replay(tick_rate: Duration, started: DateTime<Local>, records: Vec<Record>) -> Self {
+        Self {
+            tick_rate,
+            last_tick: Instant::now(),
+            replay_clock: (!records.is_empty()).then_some(started),
+            replay: records.into(),
+            replay_started: Instant::now(),
+            replay_epoch: started,
+        }
+    }
+
+    pub fn is_replaying(&self) -> bool {
+        !self.replay.is_empty()
+    }
+
+    // Sisa rekaman dibuang, misalnya saat sesi rekaman berakhir dengan quit
+    pub fn stop_replay(&mut self) {
+        self.replay.clear();
+        self.replay_clock = None;
+    }
+
+    // Jam saat input rekaman terakhir terjadi; dipasang ke App::clock selama replay
+    pub fn replay_clock(&self) -> Option<DateTime<Local>> {
+        self.replay_clock
+    }
+
+    pub fn tick_rate(&self) -> Duration {
+        self.tick_rate
+    }
+
+    // Dipakai loop utama untuk menggambar lebih sering selama animasi
+    pub fn set_tick_rate(&mut self, tick_rate: Duration) {
+        self.tick_rate = tick_rate;
+    }
+
+    // Tunggu input sampai tick berikutnya jatuh tempo
+    pub fn next_event(&mut self) -> io::Result<AppEvent> {
+        if self.is_replaying() {
+            return self.next_replayed();
+        }
+        loop {
+            let timeout = self.tick_rate.saturating_sub(self.last_tick.elapsed());
+            if event::poll(timeout)? {
+                match from_terminal(event::read()?) {
+                    Some(event) => return Ok(event),
+                    None => continue,
+                }
+            }
+            self.last_tick = Instant::now();
+            return Ok(AppEvent::Tick);
+        }
+    }
+
+    fn next_replayed(&mut self) -> io::Result<AppEvent> {
+        loop {
+            let tick_due = self.tick_rate.saturating_sub(self.last_tick.elapsed());
+            let record_due = self.replay.front().map_or(Duration::ZERO, |record| {
+                record.at().saturating_sub(self.replay_started.elapsed())
+            });
+            if record_due.is_zero() {
+                if let Some(record) = self.replay.pop_front() {
+                    self.replay_clock = Some(session::time_at(self.replay_epoch, record.at_ms));
+                    return Ok(record.event);
+                }
+            }
+            if tick_due.is_zero() {
+                self.last_tick = Instant::now();
+                return Ok(AppEvent::Tick);
+            }
+            if event::poll(tick_due.min(record_due))? {
+                event::read()?;
+            }
+        }
+    }
+}
+
+// Event dari terminal yang diteruskan ke aplikasi. Di Windows setiap tombol datang
+// dua kali (tekan dan lepas), jadi hanya tekan yang dipakai. Gerakan mouse diabaikan
+// supaya tidak memicu render terus-menerus.
+pub fn from_terminal(event: Event) -> Option<AppEvent> {
+    match event {
+        Event::Key(key) if key.kind == KeyEventKind::Press => Some(AppEvent::Key(key)),
+        Event::Resize(width, height) => Some(AppEvent::Resize(width, height)),
+        Event::Mouse(mouse) if is_handled_mouse(&mouse) => Some(AppEvent::Mouse(mouse)),
+        _ => None,
+    }
+}
+
+fn is_handled_mouse(mouse: &MouseEvent) -> bool {
+    matches!(
+        mouse.kind,
+        MouseEventKind::Down(MouseButton::Left)
+            | MouseEventKind::ScrollDown
+            | MouseEventKind::ScrollUp
+    )
+}
+
+// Klik dan scroll hanya berlaku di mode normal; popup dan input tetap lewat keyboard
+pub fn handle_mouse(app: &mut App, mouse: MouseEvent) {
+    if app.locked || app.input_mode != InputMode::Normal {
+        return;
+    }
+    app.last_input = Instant::now();
+    app.message = None;
+    app.pending_keys.clear();
+    match mouse.kind {
+        MouseEventKind::Down(MouseButton::Left) => app.click(mouse.column, mouse.row),
+        MouseEventKind::ScrollDown => app.scroll_at(true, mouse.column, mouse.row),
+        MouseEventKind::ScrollUp => app.scroll_at(false, mouse.column, mouse.row),
+        _ => {}
+    }
+    app.keep_selection_visible();
+}
+
+// Terapkan satu penekanan tombol ke state aplikasi
+pub fn handle_key(app: &mut App, key: KeyEvent) {
+    app.last_input = Instant::now();
+    if app.locked {
+        // Tombol pertama hanya membuka kunci
+        app.locked = false;
+        return;
+    }
+    app.message = None;
+    match app.input_mode {
+        InputMode::Normal => handle_normal_key(app, key),
+        InputMode::ConfirmDeleteTab => match key.code {
+            KeyCode::Char('y') | KeyCode::Char('Y') => app.delete_active_tab(),
+            _ => app.cancel_input(),
+        },
+        // Tombol apa pun menutup popup QR
+        InputMode::ShowingQr => app.cancel_input(),
+        InputMode::ShowingHelp(_) => match key.code {
+            KeyCode::Esc | KeyCode::Char('?') | KeyCode::Char('q') => app.cancel_input(),
+            KeyCode::Down | KeyCode::Char('j') => app.scroll_help(true),
+            KeyCode::Up | KeyCode::Char('k') => app.scroll_help(false),
+            _ => {}
+        },
+        InputMode::SwitchingTab(_) => match key.code {
+            KeyCode::Enter => app.confirm_switch_tab(),
+            KeyCode::Esc => app.cancel_input(),
+            KeyCode::Down | KeyCode::Tab | KeyCode::Char('`') => app.move_switcher_cursor(true),
+            KeyCode::Up | KeyCode::BackTab => app.move_switcher_cursor(false),
+            _ => {}
+        },
+        InputMode::PickingTab { .. } => match key.code {
+            KeyCode::Enter => app.confirm_pick_tab(None),
+            KeyCode::Esc => app.cancel_input(),
+            KeyCode::Char(c @ '1'..='9') => {
+                app.confirm_pick_tab(Some(c as usize - '1' as usize));
+            }
+            KeyCode::Down | KeyCode::Tab | KeyCode::Char('j') => app.move_picker_cursor(true),
+            KeyCode::Up | KeyCode::BackTab | KeyCode::Char('k') => app.move_picker_cursor(false),
+            _ => {}
+        },
+        InputMode::TagSidebar(_) => match key.code {
+            KeyCode::Enter => app.apply_tag_cursor(),
+            KeyCode::Esc => app.cancel_input(),
+            KeyCode::Char('t') => app.hide_tag_sidebar(),
+            KeyCode::Down | KeyCode::Char('j') => app.move_tag_cursor(true),
+            KeyCode::Up | KeyCode::Char('k') => app.move_tag_cursor(false),
+            _ => {}
+        },
+        InputMode::Filtering => match key.code {
+            KeyCode::Esc => app.clear_filter(),
+            _ => {
+                handle_input_key(app, key);
+                app.update_filter();
+            }
+        },
+        InputMode::GlobalSearch(_) => match key.code {
+            KeyCode::Enter => app.jump_to_search_result(),
+            KeyCode::Esc => app.cancel_input(),
+            KeyCode::Down | KeyCode::Tab => app.move_search_cursor(true),
+            KeyCode::Up | KeyCode::BackTab => app.move_search_cursor(false),
+            _ => {
+                handle_input_key(app, key);
+                app.reset_search_cursor();
+            }
+        },
+        InputMode::Command => match key.code {
+            KeyCode::Enter => app.run_command(),
+            KeyCode::Tab => app.complete_command(),
+            _ => handle_input_key(app, key),
+        },
+        InputMode::Adding
+        | InputMode::Editing(_)
+        | InputMode::EditingNotes(_)
+        | InputMode::Tagging(_)
+        | InputMode::NewTab
+        | InputMode::RenamingTab => handle_input_key(app, key),
+    }
+    // Tugas baru/hasil undo bisa saja tidak cocok dengan filter aktif
+    app.keep_selection_visible();
+}
+
+fn handle_input_key(app: &mut App, key: KeyEvent) {
+    match key.code {
+        KeyCode::Enter => app.submit_input(),
+        KeyCode::Esc => app.cancel_input(),
+        KeyCode::Backspace => {
+            app.input.pop();
+        }
+        KeyCode::Char(c) => app.input.push(c),
+        _ => {}
+    }
+}
+
+fn handle_normal_key(app: &mut App, key: KeyEvent) {
+    let Some(action) = app.keymap.action(&mut app.pending_keys, key) else {
+        return;
+    };
+    match action {
+        Action::Quit => app.should_quit = true,
+        Action::Help => app.show_help(),
+        Action::AddTask => app.start_adding(),
+        Action::EditTask => app.start_editing(),
+        Action::EditNotes => app.start_editing_notes(),
+        Action::DeleteTask => app.delete_selected(),
+        Action::ToggleDone => app.toggle_selected(),
+        Action::NextStatus => app.shift_status(true),
+        Action::PreviousStatus => app.shift_status(false),
+        Action::RaisePriority => app.cycle_priority(true),
+        Action::LowerPriority => app.cycle_priority(false),
+        Action::SortByPriority => app.sort_by_priority(),
+        Action::MoveToTab => app.start_picking_tab(false),
+        Action::CopyToTab => app.start_picking_tab(true),
+        Action::TagTask => app.start_tagging(),
+        Action::MoveTaskUp => app.move_selected(false),
+        Action::MoveTaskDown => app.move_selected(true),
+        Action::Undo => app.undo(),
+        Action::Redo => app.redo(),
+        Action::Filter => app.start_filtering(),
+        Action::ClearFilter => app.clear_filter(),
+        Action::GlobalSearch => app.start_global_search(),
+        Action::ToggleTagSidebar => app.focus_tag_sidebar(),
+        // Toggle detail visibility
+        Action::ToggleDetails => app.toggle_details(),
+        Action::ShowQr => app.show_qr(),
+        Action::ScrollDetailsDown => app.scroll_details(true, false),
+        Action::ScrollDetailsUp => app.scroll_details(false, false),
+        Action::PageDetailsDown => app.scroll_details(true, true),
+        Action::PageDetailsUp => app.scroll_details(false, true),
+        Action::TogglePrivacy => app.privacy_mode = !app.privacy_mode,
+        Action::ToggleDebug => app.debug.visible = !app.debug.visible,
+        Action::CycleTheme => app.cycle_theme(),
+        Action::Down => app.select_next(true),
+        Action::Up => app.select_next(false),
+        Action::Top => app.select_edge(false),
+        Action::Bottom => app.select_edge(true),
+        // Selama panel detail tampil, PageUp/PageDown menggulir panel itu (seperti
+        // ctrl+u/ctrl+d); kalau tidak, berpindah satu halaman di daftar tugas
+        Action::PageDown if app.current_tab().show_details => app.scroll_details(true, true),
+        Action::PageUp if app.current_tab().show_details => app.scroll_details(false, true),
+        Action::PageDown => app.page_selection(true),
+        Action::PageUp => app.page_selection(false),
+        Action::CommandLine => app.start_command(),
+        Action::NextTab => app.cycle_tab(true),
+        Action::PreviousTab => app.cycle_tab(false),
+        Action::RecentTabs => app.start_switching_tab(),
+        Action::NewTab => app.start_new_tab(),
+        Action::RenameTab => app.start_renaming_tab(),
+        Action::DeleteTab => app.start_deleting_tab(),
+        Action::SelectTab(index) => app.select_tab(index),
+    }
+}
@@ -0,0 +1,63 @@
+pub mod ical;
+pub mod markdown;
+pub mod todotxt;
+
+use crate::storage::{self, Database, TabRecord};
+use chrono::{DateTime, Local};
+use std::{fs, io, path::Path};
+
+// Format file ekspor, ditebak dari ekstensi path
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Json,
+    Markdown,
+    TodoTxt,
+    Ical,
+}
+
+impl Format {
+    // .md/.markdown -> Markdown, .txt -> todo.txt, .ics -> iCalendar,
+    // selain itu JSON seperti file data
+    pub fn from_path(path: &Path) -> Self {
+        let extension = path
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .map(str::to_lowercase);
+        match extension.as_deref() {
+            Some("md" | "markdown") => Format::Markdown,
+            Some("txt") => Format::TodoTxt,
+            Some("ics" | "ical") => Format::Ical,
+            _ => Format::Json,
+        }
+    }
+}
+
+// Hasilnya jumlah tugas yang ditulis; iCalendar hanya memuat tugas dengan jatuh tempo.
+// `now` dipakai sebagai waktu ekspor di file iCalendar. Catatan di `tabs` harus
+// sudah berupa teks (App::to_database_with_notes).
+pub fn write(path: &Path, tabs: Vec<TabRecord>, now: DateTime<Local>) -> io::Result<usize> {
+    let mut count: usize = tabs.iter().map(|tab| tab.tasks.len()).sum();
+    let contents = match Format::from_path(path) {
+        Format::Json => {
+            storage::save(
+                path,
+                &Database {
+                    tabs,
+                    notes_file: None,
+                },
+            )?;
+            return Ok(count);
+        }
+        Format::Markdown => markdown::render(&tabs),
+        Format::TodoTxt => todotxt::render(&tabs),
+        Format::Ical => {
+            count = ical::count(&tabs);
+            ical::render(&tabs, now)
+        }
+    };
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, contents)?;
+    Ok(count)
+}
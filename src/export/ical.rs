@@ -0,0 +1,163 @@
+use crate::{
+    app::{Priority, Task},
+    storage::TabRecord,
+};
+use chrono::{DateTime, Local, Utc};
+use std::collections::HashMap;
+
+// iCalendar (RFC 5545), satu VTODO per tugas yang punya jatuh tempo:
+//
+//   BEGIN:VTODO
+//   UID:3f2a9c1e5b7d4e80@firsttui
+//   DTSTAMP:20240502T080000Z
+//   CREATED:20240501T023000Z
+//   SUMMARY:Finish report #work
+//   DESCRIPTION:first line\n\nthird line
+//   DUE;VALUE=DATE:20240503
+//   PRIORITY:1
+//   STATUS:NEEDS-ACTION
+//   CATEGORIES:Work,work
+//   END:VTODO
+//
+// Tugas tanpa jatuh tempo dilewati karena tidak muncul di kalender.
+// `now` jadi DTSTAMP (waktu ekspor).
+pub fn render(tabs: &[TabRecord], now: DateTime<Local>) -> String {
+    let mut lines = vec![
+        "BEGIN:VCALENDAR".to_string(),
+        "VERSION:2.0".to_string(),
+        "PRODID:-//firsttui//tasks//EN".to_string(),
+    ];
+    for tab in tabs {
+        // Berapa kali identitas yang sama sudah muncul di tab ini (salinan tugas,
+        // atau tugas tanpa tanggal dibuat dengan judul yang sama)
+        let mut seen: HashMap<String, usize> = HashMap::new();
+        for task in &tab.tasks {
+            let identity = identity(task);
+            let occurrence = seen.entry(identity.clone()).or_default();
+            if task.due.is_some() {
+                let uid = uid(&tab.name, &identity, *occurrence);
+                lines.extend(vtodo(task, tab, &uid, now));
+            }
+            *occurrence += 1;
+        }
+    }
+    lines.push("END:VCALENDAR".to_string());
+    lines.iter().map(|line| fold(line)).collect()
+}
+
+// Jumlah tugas yang ikut ditulis oleh `render`
+pub fn count(tabs: &[TabRecord]) -> usize {
+    tabs.iter()
+        .flat_map(|tab| &tab.tasks)
+        .filter(|task| task.due.is_some())
+        .count()
+}
+
+fn vtodo(task: &Task, tab: &TabRecord, uid: &str, now: DateTime<Local>) -> Vec<String> {
+    let mut lines = vec![
+        "BEGIN:VTODO".to_string(),
+        format!("UID:{}", uid),
+        format!("DTSTAMP:{}", utc_stamp(now)),
+    ];
+    if let Some(created) = task.created {
+        lines.push(format!("CREATED:{}", utc_stamp(created)));
+    }
+    lines.push(format!("SUMMARY:{}", escape(&task.title)));
+    if let Some(notes) = task.notes.loaded().filter(|notes| !notes.is_empty()) {
+        lines.push(format!("DESCRIPTION:{}", escape(notes)));
+    }
+    if let Some(due) = task.due {
+        lines.push(format!("DUE;VALUE=DATE:{}", due.format("%Y%m%d")));
+    }
+    // 1 = paling tinggi, 9 = paling rendah; tanpa prioritas tidak ditulis (0 = tidak ditentukan)
+    let priority = match task.priority {
+        Priority::High => Some(1),
+        Priority::Medium => Some(5),
+        Priority::Low => Some(9),
+        Priority::None => None,
+    };
+    if let Some(priority) = priority {
+        lines.push(format!("PRIORITY:{}", priority));
+    }
+    lines.push(format!("STATUS:{}", status(task, &tab.statuses)));
+    // Nama tab lalu tag tugas, supaya bisa disaring di aplikasi kalender
+    let categories: Vec<String> = std::iter::once(tab.name.clone())
+        .chain(task.tags())
+        .map(|category| escape(&category))
+        .collect();
+    lines.push(format!("CATEGORIES:{}", categories.join(",")));
+    lines.push("END:VTODO".to_string());
+    lines
+}
+
+// Status khusus di antara yang pertama dan yang terakhir dianggap sedang dikerjakan
+fn status(task: &Task, statuses: &[String]) -> &'static str {
+    if task.done {
+        "COMPLETED"
+    } else if !statuses.is_empty() && task.status_index(statuses) > 0 {
+        "IN-PROCESS"
+    } else {
+        "NEEDS-ACTION"
+    }
+}
+
+// UID harus tetap sama di ekspor berikutnya supaya aplikasi kalender memperbarui
+// tugas yang sudah ada, bukan menambah duplikat. Dasarnya waktu dibuat (atau judul
+// kalau tidak ada), nama tab karena salinan di tab lain punya waktu dibuat yang sama,
+// dan urutan kemunculan identitas itu di tabnya. Urutan tugas lain tidak berpengaruh.
+fn identity(task: &Task) -> String {
+    match task.created {
+        Some(created) => created.to_rfc3339(),
+        None => task.title.clone(),
+    }
+}
+
+fn uid(tab: &str, identity: &str, occurrence: usize) -> String {
+    let hash = format!("{}\n{}\n{}", tab, identity, occurrence)
+        .bytes()
+        .fold(0xcbf2_9ce4_8422_2325u64, |hash, byte| {
+            (hash ^ byte as u64).wrapping_mul(0x0100_0000_01b3)
+        });
+    format!("{:016x}@firsttui", hash)
+}
+
+fn utc_stamp(time: DateTime<Local>) -> String {
+    time.with_timezone(&Utc)
+        .format("%Y%m%dT%H%M%SZ")
+        .to_string()
+}
+
+// Teks bebas: backslash, titik koma, koma dan baris baru harus di-escape
+fn escape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '\\' | ';' | ',' => {
+                out.push('\\');
+                out.push(c);
+            }
+            '\n' => out.push_str("\\n"),
+            '\r' => {}
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+// Baris lebih dari 75 byte dilipat: CRLF lalu spasi, tanpa memotong karakter UTF-8
+fn fold(line: &str) -> String {
+    const LIMIT: usize = 75;
+    let mut out = String::with_capacity(line.len() + 2);
+    let mut width = 0;
+    for c in line.chars() {
+        // Baris lanjutan diawali spasi yang ikut dihitung
+        if width + c.len_utf8() > LIMIT {
+            out.push_str("\r\n ");
+            width = 1;
+        }
+        out.push(c);
+        width += c.len_utf8();
+    }
+    out.push_str("\r\n");
+    out
+}
@@ -0,0 +1,52 @@
+use crate::{app::Task, storage::TabRecord};
+
+// Satu judul per tab lalu checklist, misalnya:
+//
+//   ## Work
+//
+//   - [ ] Finish report #work (priority: high, due: 2024-05-03)
+//     > catatan, satu baris per baris
+//   - [x] Email manager
+pub fn render(tabs: &[TabRecord]) -> String {
+    let mut out = String::new();
+    for (index, tab) in tabs.iter().enumerate() {
+        if index > 0 {
+            out.push('\n');
+        }
+        out.push_str(&format!("## {}\n\n", tab.name));
+        for task in &tab.tasks {
+            out.push_str(&task_line(task, &tab.statuses));
+            for line in task.notes.loaded().unwrap_or_default().lines() {
+                if line.is_empty() {
+                    out.push_str("  >\n");
+                } else {
+                    out.push_str(&format!("  > {}\n", line));
+                }
+            }
+        }
+    }
+    out
+}
+
+fn task_line(task: &Task, statuses: &[String]) -> String {
+    let check = if task.done { 'x' } else { ' ' };
+    let mut details = Vec::new();
+    if !statuses.is_empty() {
+        details.push(format!("status: {}", task.status_label(statuses)));
+    }
+    if !task.priority.is_none() {
+        details.push(format!(
+            "priority: {}",
+            task.priority.label().to_lowercase()
+        ));
+    }
+    if let Some(due) = task.due {
+        details.push(format!("due: {}", due.format("%Y-%m-%d")));
+    }
+    let title = task.title.replace('\n', " ");
+    if details.is_empty() {
+        format!("- [{}] {}\n", check, title)
+    } else {
+        format!("- [{}] {} ({})\n", check, title, details.join(", "))
+    }
+}
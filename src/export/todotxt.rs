@@ -0,0 +1,274 @@
+use crate::{
+    app::{tag_name, Priority, Task},
+    storage::TabRecord,
+};
+use chrono::{Local, NaiveDate};
+
+// Format todo.txt (http://todotxt.org), satu tugas per baris:
+//
+//   (A) 2024-05-01 Finish report +work due:2024-05-03
+//   x Email manager pri:B
+//
+// Tag #work ditulis sebagai +work. Tugas selesai tidak membawa tanggal (tanggal
+// pertama setelah "x" berarti tanggal selesai, yang tidak kita simpan), dan
+// prioritasnya ditulis sebagai pri:X. Kalau ada lebih dari satu tab, setiap
+// baris diberi tab:Nama supaya bisa diimpor kembali ke tab yang sama; spasi dan
+// "%" di nama tab ditulis sebagai %20 dan %25 (lihat `encode_name`).
+pub fn render(tabs: &[TabRecord]) -> String {
+    let mut out = String::new();
+    for tab in tabs {
+        for task in &tab.tasks {
+            let priority = priority_letter(task.priority);
+            let created = task
+                .created
+                .filter(|_| !task.done)
+                .map(|created| created.format("%Y-%m-%d").to_string());
+            // Judul yang diawali "x", "(A)" atau tanggal akan terbaca sebagai penanda
+            // kalau tidak ada tanggal dibuat di depannya. Key:value di depan judul
+            // menghentikan pembacaan penanda, jadi prioritas ditulis sebagai pri:X
+            // dan tab:Nama selalu ikut supaya paling tidak ada satu key.
+            let ambiguous =
+                created.is_none() && task.title.split_whitespace().next().is_some_and(is_marker);
+            let mut words: Vec<String> = Vec::new();
+            if task.done {
+                words.push("x".to_string());
+            } else {
+                if let (false, Some(letter)) = (ambiguous, priority) {
+                    words.push(format!("({})", letter));
+                }
+                words.extend(created);
+            }
+
+            let mut keys = Vec::new();
+            if let Some(due) = task.due {
+                keys.push(format!("due:{}", due.format("%Y-%m-%d")));
+            }
+            if let (true, Some(letter)) = (task.done || ambiguous, priority) {
+                keys.push(format!("pri:{}", letter));
+            }
+            if tabs.len() > 1 || ambiguous {
+                keys.push(format!("tab:{}", encode_name(&tab.name)));
+            }
+            if ambiguous {
+                words.append(&mut keys);
+            }
+            words.extend(
+                task.title
+                    .split_whitespace()
+                    .map(|word| match tag_name(word) {
+                        Some(tag) => format!("+{}", tag),
+                        None => word.to_string(),
+                    }),
+            );
+            words.append(&mut keys);
+            out.push_str(&words.join(" "));
+            out.push('\n');
+        }
+    }
+    out
+}
+
+// Tugas tanpa tab:Nama masuk ke tab `default_tab`. Baris yang tidak bisa dibaca
+// tidak pernah menggagalkan impor: bagian yang tidak dikenal tetap jadi judul.
+pub fn parse(text: &str, default_tab: &str) -> Vec<TabRecord> {
+    let mut tabs: Vec<TabRecord> = Vec::new();
+    for line in text.lines() {
+        let Some((tab, task)) = parse_line(line) else {
+            continue;
+        };
+        let tab = tab.unwrap_or_else(|| default_tab.to_string());
+        match tabs.iter_mut().find(|record| record.name == tab) {
+            Some(record) => record.tasks.push(task),
+            None => tabs.push(TabRecord {
+                name: tab,
+                statuses: Vec::new(),
+                tasks: vec![task],
+            }),
+        }
+    }
+    tabs
+}
+
+fn parse_line(line: &str) -> Option<(Option<String>, Task)> {
+    let words: Vec<&str> = line.split_whitespace().collect();
+    let (mut markers, consumed) = Markers::parse(&words);
+    let mut description = Description::parse(&words[consumed..]);
+    if description.title.is_empty() {
+        // Baris yang hanya berisi penanda, misalnya "x" atau "(A)", tetap jadi judul
+        markers = Markers::default();
+        description = Description::parse(&words);
+    }
+    if description.title.is_empty() {
+        return None;
+    }
+    let created = markers
+        .created
+        .and_then(|date| date.and_hms_opt(0, 0, 0))
+        .and_then(|date| date.and_local_timezone(Local).earliest());
+    let task = Task {
+        done: markers.done,
+        created,
+        due: description.due,
+        priority: markers
+            .priority
+            .or(description.priority)
+            .unwrap_or_default(),
+        ..Task::new(description.title.join(" "))
+    };
+    Some((description.tab, task))
+}
+
+// Penanda di awal baris: "x", "(A)" dan tanggal
+#[derive(Default)]
+struct Markers {
+    done: bool,
+    priority: Option<Priority>,
+    created: Option<NaiveDate>,
+}
+
+impl Markers {
+    // Penanda dan jumlah kata yang dipakai
+    fn parse(words: &[&str]) -> (Self, usize) {
+        let mut markers = Markers::default();
+        let mut rest = words;
+        if let Some((&"x", tail)) = rest.split_first() {
+            markers.done = true;
+            rest = tail;
+            // Tanggal selesai lalu tanggal dibuat; tanggal selesai tidak disimpan
+            if let Some((word, tail)) = rest.split_first() {
+                if parse_date(word).is_some() {
+                    rest = tail;
+                }
+            }
+        } else if let Some((word, tail)) = rest.split_first() {
+            markers.priority = parse_priority(word);
+            if markers.priority.is_some() {
+                rest = tail;
+            }
+        }
+        if let Some((word, tail)) = rest.split_first() {
+            markers.created = parse_date(word);
+            if markers.created.is_some() {
+                rest = tail;
+            }
+        }
+        (markers, words.len() - rest.len())
+    }
+}
+
+// Sisa baris: judul plus key:value yang dikenal
+#[derive(Default)]
+struct Description {
+    title: Vec<String>,
+    due: Option<NaiveDate>,
+    priority: Option<Priority>,
+    tab: Option<String>,
+}
+
+impl Description {
+    fn parse(words: &[&str]) -> Self {
+        let mut description = Description::default();
+        for word in words {
+            if let Some(date) = word.strip_prefix("due:").and_then(parse_date) {
+                description.due = Some(date);
+            } else if let Some(priority) = word.strip_prefix("pri:").and_then(priority_from_letter)
+            {
+                description.priority = Some(priority);
+            } else if let Some(name) = word.strip_prefix("tab:").filter(|name| !name.is_empty()) {
+                description.tab = Some(decode_name(name));
+            } else if let Some(tag) = word.strip_prefix(['+', '@']).filter(|tag| !tag.is_empty()) {
+                description.title.push(format!("#{}", tag));
+            } else {
+                description.title.push(word.to_string());
+            }
+        }
+        description
+    }
+}
+
+// Nama tab harus satu kata: spasi (dan whitespace lain) di-percent-encode, begitu
+// juga "%" sendiri supaya bisa dibalik persis. Karakter lain ditulis apa adanya.
+fn encode_name(name: &str) -> String {
+    let mut out = String::with_capacity(name.len());
+    for c in name.chars() {
+        if c == '%' || c.is_whitespace() {
+            let mut buf = [0; 4];
+            for byte in c.encode_utf8(&mut buf).bytes() {
+                out.push_str(&format!("%{:02X}", byte));
+            }
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
+// Kebalikan `encode_name`; "%" yang tidak diikuti dua digit hex dibiarkan
+fn decode_name(word: &str) -> String {
+    let bytes = word.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut index = 0;
+    while index < bytes.len() {
+        let hex = bytes
+            .get(index + 1..index + 3)
+            .filter(|hex| hex.iter().all(u8::is_ascii_hexdigit))
+            .and_then(|hex| std::str::from_utf8(hex).ok())
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+        match (bytes[index], hex) {
+            (b'%', Some(byte)) => {
+                out.push(byte);
+                index += 3;
+            }
+            (byte, _) => {
+                out.push(byte);
+                index += 1;
+            }
+        }
+    }
+    String::from_utf8_lossy(&out).into_owned()
+}
+
+// Kata yang dibaca sebagai penanda kalau ada di awal baris
+fn is_marker(word: &str) -> bool {
+    word == "x" || parse_priority(word).is_some() || parse_date(word).is_some()
+}
+
+fn priority_letter(priority: Priority) -> Option<char> {
+    match priority {
+        Priority::High => Some('A'),
+        Priority::Medium => Some('B'),
+        Priority::Low => Some('C'),
+        Priority::None => None,
+    }
+}
+
+// "(A)" di awal baris
+fn parse_priority(word: &str) -> Option<Priority> {
+    word.strip_prefix('(')?
+        .strip_suffix(')')
+        .and_then(priority_from_letter)
+}
+
+// A = High, B = Medium, C sampai Z = Low
+fn priority_from_letter(letter: &str) -> Option<Priority> {
+    match letter {
+        "A" => Some(Priority::High),
+        "B" => Some(Priority::Medium),
+        _ if letter.len() == 1 && letter.chars().all(|c| c.is_ascii_uppercase()) => {
+            Some(Priority::Low)
+        }
+        _ => None,
+    }
+}
+
+// Hanya YYYY-MM-DD persis; chrono juga menerima "+2024-1-5", yang di todo.txt
+// bisa berupa +project
+fn parse_date(word: &str) -> Option<NaiveDate> {
+    let digits = word.bytes().enumerate().all(|(index, byte)| {
+        matches!(index, 4 | 7) == (byte == b'-') && (byte == b'-' || byte.is_ascii_digit())
+    });
+    if word.len() != 10 || !digits {
+        return None;
+    }
+    NaiveDate::parse_from_str(word, "%Y-%m-%d").ok()
+}
@@ -0,0 +1,151 @@
+use crate::app::Task;
+use serde::{Deserialize, Serialize};
+use std::mem;
+
+// Batas jumlah langkah undo yang disimpan
+const MAX_HISTORY: usize = 100;
+
+// Satu perubahan tugas yang bisa dibatalkan; `tab` dan `index` menunjuk posisinya
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum Action {
+    Add {
+        tab: usize,
+        index: usize,
+        task: Task,
+    },
+    Delete {
+        tab: usize,
+        index: usize,
+        task: Task,
+    },
+    Update {
+        tab: usize,
+        index: usize,
+        before: Task,
+        after: Task,
+    },
+    // Tugas pindah ke tab lain
+    Transfer {
+        from_tab: usize,
+        from_index: usize,
+        to_tab: usize,
+        to_index: usize,
+        task: Task,
+    },
+    // Tugas di `from` ditukar dengan tetangganya di `to`
+    Swap {
+        tab: usize,
+        from: usize,
+        to: usize,
+    },
+    // Urutan seluruh daftar berubah, misalnya setelah sorting. Disimpan sebagai
+    // permutasi, bukan salinan tugas: tugas ke-i yang baru = tugas ke-order[i] yang lama
+    Reorder {
+        tab: usize,
+        order: Vec<usize>,
+    },
+}
+
+impl Action {
+    // Aksi yang membatalkan aksi ini
+    pub fn inverse(self) -> Action {
+        match self {
+            Action::Add { tab, index, task } => Action::Delete { tab, index, task },
+            Action::Delete { tab, index, task } => Action::Add { tab, index, task },
+            Action::Update {
+                tab,
+                index,
+                before,
+                after,
+            } => Action::Update {
+                tab,
+                index,
+                before: after,
+                after: before,
+            },
+            Action::Transfer {
+                from_tab,
+                from_index,
+                to_tab,
+                to_index,
+                task,
+            } => Action::Transfer {
+                from_tab: to_tab,
+                from_index: to_index,
+                to_tab: from_tab,
+                to_index: from_index,
+                task,
+            },
+            Action::Swap { tab, from, to } => Action::Swap {
+                tab,
+                from: to,
+                to: from,
+            },
+            Action::Reorder { tab, order } => {
+                let mut inverse = vec![0; order.len()];
+                for (new, &old) in order.iter().enumerate() {
+                    inverse[old] = new;
+                }
+                Action::Reorder {
+                    tab,
+                    order: inverse,
+                }
+            }
+        }
+    }
+
+    fn heap_size(&self) -> usize {
+        match self {
+            Action::Add { task, .. }
+            | Action::Delete { task, .. }
+            | Action::Transfer { task, .. } => task.heap_size(),
+            Action::Update { before, after, .. } => before.heap_size() + after.heap_size(),
+            Action::Swap { .. } => 0,
+            Action::Reorder { order, .. } => order.capacity() * mem::size_of::<usize>(),
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct History {
+    undo: Vec<Action>,
+    redo: Vec<Action>,
+}
+
+impl History {
+    // Perubahan baru membuang riwayat redo
+    pub fn record(&mut self, action: Action) {
+        if self.undo.len() == MAX_HISTORY {
+            self.undo.remove(0);
+        }
+        self.undo.push(action);
+        self.redo.clear();
+    }
+
+    // Mengembalikan aksi yang harus diterapkan untuk membatalkan langkah terakhir
+    pub fn undo(&mut self) -> Option<Action> {
+        let action = self.undo.pop()?;
+        self.redo.push(action.clone());
+        Some(action.inverse())
+    }
+
+    pub fn redo(&mut self) -> Option<Action> {
+        let action = self.redo.pop()?;
+        self.undo.push(action.clone());
+        Some(action)
+    }
+
+    // Perkiraan byte untuk laporan memori di overlay debug
+    pub fn memory_estimate(&self) -> usize {
+        self.undo
+            .iter()
+            .chain(&self.redo)
+            .map(|action| mem::size_of::<Action>() + action.heap_size())
+            .sum()
+    }
+
+    pub fn clear(&mut self) {
+        self.undo.clear();
+        self.redo.clear();
+    }
+}
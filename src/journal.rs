@@ -0,0 +1,112 @@
+use crate::history::Action;
+use serde::{Deserialize, Serialize};
+use std::{
+    fs::{self, File, OpenOptions},
+    io::{self, BufRead, BufReader, Write},
+    path::{Path, PathBuf},
+};
+
+// Satu perubahan data sejak penyimpanan terakhir, satu baris JSON per entri
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum Entry {
+    Action(Action), // Termasuk aksi yang diterapkan oleh undo/redo
+    NewTab(String),
+    RenameTab { tab: usize, name: String },
+    SetStatuses { tab: usize, statuses: Vec<String> },
+    DeleteTab(usize),
+}
+
+// Baris pertama journal: checksum file data tempat entri-entri berikutnya berlaku
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+struct Header {
+    base: u64,
+}
+
+// Journal ditulis di samping file data dan dihapus setelah simpan normal saat keluar.
+// Kalau masih ada saat start, berarti proses terakhir mati sebelum sempat menyimpan.
+// Kalau matinya di antara simpan dan hapus journal, file data sudah berubah dan tidak
+// cocok lagi dengan header, jadi entrinya tidak diterapkan dua kali.
+pub struct Journal {
+    file: File,
+}
+
+impl Journal {
+    // tasks.json -> tasks.json.journal
+    pub fn path_for(data_file: &Path) -> PathBuf {
+        let mut path = data_file.as_os_str().to_owned();
+        path.push(".journal");
+        PathBuf::from(path)
+    }
+
+    // Journal baru yang kosong untuk file data dengan checksum `base` (lihat `checksum`)
+    pub fn create(path: &Path, base: u64) -> io::Result<Self> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let file = OpenOptions::new()
+            .create(true)
+            .write(true)
+            .truncate(true)
+            .open(path)?;
+        let mut journal = Self { file };
+        journal.write_line(&Header { base })?;
+        Ok(journal)
+    }
+
+    // Langsung ke file tanpa buffer, jadi tetap ada walau proses di-kill -9
+    pub fn append(&mut self, entry: &Entry) -> io::Result<()> {
+        self.write_line(entry)
+    }
+
+    fn write_line<T: Serialize>(&mut self, value: &T) -> io::Result<()> {
+        let mut line = serde_json::to_vec(value)
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+        line.push(b'\n');
+        self.file.write_all(&line)
+    }
+}
+
+// FNV-1a dari isi file data (lihat `storage::read`); file yang belum ada pakai 0
+pub fn checksum(contents: &[u8]) -> u64 {
+    contents
+        .iter()
+        .fold(0xcbf2_9ce4_8422_2325u64, |hash, byte| {
+            (hash ^ *byte as u64).wrapping_mul(0x0100_0000_01b3)
+        })
+}
+
+// Ok(None) kalau tidak ada journal (shutdown terakhir normal). Journal yang dibuat
+// untuk isi file data lain (`base` tidak sama dengan `checksum` sekarang) sudah
+// tersimpan, jadi hasilnya kosong. Journal versi lama tanpa header tetap dibaca.
+// Baris terakhir yang terpotong karena crash di tengah penulisan diabaikan.
+pub fn read(path: &Path, base: u64) -> io::Result<Option<Vec<Entry>>> {
+    let file = match File::open(path) {
+        Ok(file) => file,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(None),
+        Err(err) => return Err(err),
+    };
+    let mut lines = BufReader::new(file).lines().peekable();
+    if let Some(Ok(first)) = lines.peek() {
+        if let Ok(header) = serde_json::from_str::<Header>(first) {
+            if header.base != base {
+                return Ok(Some(Vec::new()));
+            }
+            lines.next();
+        }
+    }
+    let mut entries = Vec::new();
+    for line in lines {
+        match serde_json::from_str(&line?) {
+            Ok(entry) => entries.push(entry),
+            Err(_) => break,
+        }
+    }
+    Ok(Some(entries))
+}
+
+pub fn remove(path: &Path) -> io::Result<()> {
+    match fs::remove_file(path) {
+        Err(err) if err.kind() != io::ErrorKind::NotFound => Err(err),
+        _ => Ok(()),
+    }
+}
@@ -0,0 +1,596 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use serde::Deserialize;
+use std::{collections::HashMap, fmt};
+
+// Aksi yang bisa dipicu tombol di mode normal
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Action {
+    Quit,
+    AddTask,
+    EditTask,
+    EditNotes,
+    DeleteTask,
+    ToggleDone,
+    NextStatus,
+    PreviousStatus,
+    RaisePriority,
+    LowerPriority,
+    SortByPriority,
+    MoveTaskUp,
+    MoveTaskDown,
+    MoveToTab,
+    CopyToTab,
+    TagTask,
+    Undo,
+    Redo,
+    Filter,
+    ClearFilter,
+    GlobalSearch,
+    ToggleTagSidebar,
+    ToggleDetails,
+    ShowQr,
+    ScrollDetailsDown,
+    ScrollDetailsUp,
+    PageDetailsDown,
+    PageDetailsUp,
+    TogglePrivacy,
+    ToggleDebug,
+    CycleTheme,
+    Down,
+    Up,
+    Top,
+    Bottom,
+    PageDown,
+    PageUp,
+    CommandLine,
+    NextTab,
+    PreviousTab,
+    RecentTabs,
+    NewTab,
+    RenameTab,
+    DeleteTab,
+    SelectTab(usize), // Index tab, 0 = tab pertama
+    Help,
+}
+
+// Kelompok aksi di popup bantuan
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Category {
+    Navigation,
+    Editing,
+    Tabs,
+    Misc,
+}
+
+impl Category {
+    pub const ALL: [Category; 4] = [
+        Category::Navigation,
+        Category::Editing,
+        Category::Tabs,
+        Category::Misc,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Category::Navigation => "Navigation",
+            Category::Editing => "Editing",
+            Category::Tabs => "Tabs",
+            Category::Misc => "Misc",
+        }
+    }
+}
+
+// Nama aksi di file config; SelectTab ditulis tab_1 sampai tab_9
+const ACTION_NAMES: [(&str, Action); 45] = [
+    ("quit", Action::Quit),
+    ("down", Action::Down),
+    ("up", Action::Up),
+    ("top", Action::Top),
+    ("bottom", Action::Bottom),
+    ("page_down", Action::PageDown),
+    ("page_up", Action::PageUp),
+    ("add_task", Action::AddTask),
+    ("edit_task", Action::EditTask),
+    ("edit_notes", Action::EditNotes),
+    ("delete_task", Action::DeleteTask),
+    ("toggle_done", Action::ToggleDone),
+    ("next_status", Action::NextStatus),
+    ("previous_status", Action::PreviousStatus),
+    ("raise_priority", Action::RaisePriority),
+    ("lower_priority", Action::LowerPriority),
+    ("sort_by_priority", Action::SortByPriority),
+    ("move_task_up", Action::MoveTaskUp),
+    ("move_task_down", Action::MoveTaskDown),
+    ("move_to_tab", Action::MoveToTab),
+    ("copy_to_tab", Action::CopyToTab),
+    ("tag_task", Action::TagTask),
+    ("undo", Action::Undo),
+    ("redo", Action::Redo),
+    ("filter", Action::Filter),
+    ("clear_filter", Action::ClearFilter),
+    ("global_search", Action::GlobalSearch),
+    ("toggle_tag_sidebar", Action::ToggleTagSidebar),
+    ("toggle_details", Action::ToggleDetails),
+    ("show_qr", Action::ShowQr),
+    ("scroll_details_down", Action::ScrollDetailsDown),
+    ("scroll_details_up", Action::ScrollDetailsUp),
+    ("page_details_down", Action::PageDetailsDown),
+    ("page_details_up", Action::PageDetailsUp),
+    ("toggle_privacy", Action::TogglePrivacy),
+    ("toggle_debug", Action::ToggleDebug),
+    ("cycle_theme", Action::CycleTheme),
+    ("command_line", Action::CommandLine),
+    ("next_tab", Action::NextTab),
+    ("previous_tab", Action::PreviousTab),
+    ("recent_tabs", Action::RecentTabs),
+    ("new_tab", Action::NewTab),
+    ("rename_tab", Action::RenameTab),
+    ("delete_tab", Action::DeleteTab),
+    ("help", Action::Help),
+];
+
+const DEFAULT_BINDINGS: [(&str, Action); 49] = [
+    ("q", Action::Quit),
+    ("a", Action::AddTask),
+    ("e", Action::EditTask),
+    ("n", Action::EditNotes),
+    ("d", Action::DeleteTask),
+    ("space", Action::ToggleDone),
+    ("]", Action::NextStatus),
+    ("[", Action::PreviousStatus),
+    ("p", Action::RaisePriority),
+    ("P", Action::LowerPriority),
+    ("s", Action::SortByPriority),
+    ("shift+up", Action::MoveTaskUp),
+    ("K", Action::MoveTaskUp),
+    ("shift+down", Action::MoveTaskDown),
+    ("J", Action::MoveTaskDown),
+    ("m", Action::MoveToTab),
+    ("c", Action::CopyToTab),
+    ("#", Action::TagTask),
+    ("u", Action::Undo),
+    ("ctrl+r", Action::Redo),
+    ("/", Action::Filter),
+    ("esc", Action::ClearFilter),
+    ("ctrl+f", Action::GlobalSearch),
+    ("t", Action::ToggleTagSidebar),
+    (":", Action::CommandLine),
+    ("enter", Action::ToggleDetails),
+    ("o", Action::ShowQr),
+    ("j", Action::ScrollDetailsDown),
+    ("k", Action::ScrollDetailsUp),
+    ("ctrl+d", Action::PageDetailsDown),
+    ("ctrl+u", Action::PageDetailsUp),
+    ("ctrl+b", Action::TogglePrivacy),
+    ("f12", Action::ToggleDebug),
+    ("ctrl+t", Action::CycleTheme),
+    ("down", Action::Down),
+    ("up", Action::Up),
+    ("home", Action::Top),
+    ("end", Action::Bottom),
+    ("pagedown", Action::PageDown),
+    ("pageup", Action::PageUp),
+    ("tab", Action::NextTab),
+    ("l", Action::NextTab),
+    ("backtab", Action::PreviousTab),
+    ("h", Action::PreviousTab),
+    ("`", Action::RecentTabs),
+    ("T", Action::NewTab),
+    ("R", Action::RenameTab),
+    ("X", Action::DeleteTab),
+    ("?", Action::Help),
+];
+
+// Lapisan mode vim; urutan beberapa tombol seperti "gg" dan "dd" diperbolehkan.
+// Tombol lain tetap memakai keymap biasa.
+const VIM_BINDINGS: [(&str, Action); 9] = [
+    ("j", Action::Down),
+    ("k", Action::Up),
+    ("gg", Action::Top),
+    ("G", Action::Bottom),
+    ("dd", Action::DeleteTask),
+    ("x", Action::ToggleDone),
+    ("i", Action::EditTask),
+    ("a", Action::AddTask),
+    (":", Action::CommandLine),
+];
+
+impl Action {
+    fn from_name(name: &str) -> Option<Action> {
+        if let Some(number) = name.strip_prefix("tab_") {
+            return match number.parse::<usize>() {
+                Ok(number @ 1..=9) => Some(Action::SelectTab(number - 1)),
+                _ => None,
+            };
+        }
+        ACTION_NAMES
+            .iter()
+            .find(|(action_name, _)| *action_name == name)
+            .map(|(_, action)| *action)
+    }
+
+    pub fn category(self) -> Category {
+        match self {
+            Action::Down
+            | Action::Up
+            | Action::Top
+            | Action::Bottom
+            | Action::PageDown
+            | Action::PageUp
+            | Action::Filter
+            | Action::ClearFilter
+            | Action::GlobalSearch
+            | Action::ToggleTagSidebar
+            | Action::ScrollDetailsDown
+            | Action::ScrollDetailsUp
+            | Action::PageDetailsDown
+            | Action::PageDetailsUp => Category::Navigation,
+            Action::AddTask
+            | Action::EditTask
+            | Action::EditNotes
+            | Action::DeleteTask
+            | Action::ToggleDone
+            | Action::NextStatus
+            | Action::PreviousStatus
+            | Action::RaisePriority
+            | Action::LowerPriority
+            | Action::SortByPriority
+            | Action::MoveTaskUp
+            | Action::MoveTaskDown
+            | Action::MoveToTab
+            | Action::CopyToTab
+            | Action::TagTask
+            | Action::Undo
+            | Action::Redo => Category::Editing,
+            Action::NextTab
+            | Action::PreviousTab
+            | Action::RecentTabs
+            | Action::NewTab
+            | Action::RenameTab
+            | Action::DeleteTab
+            | Action::SelectTab(_) => Category::Tabs,
+            Action::Quit
+            | Action::ToggleDetails
+            | Action::ShowQr
+            | Action::TogglePrivacy
+            | Action::ToggleDebug
+            | Action::CycleTheme
+            | Action::CommandLine
+            | Action::Help => Category::Misc,
+        }
+    }
+
+    pub fn description(self) -> &'static str {
+        match self {
+            Action::Quit => "Quit",
+            Action::AddTask => "Add task",
+            Action::EditTask => "Edit task title and due date",
+            Action::EditNotes => "Edit task notes",
+            Action::DeleteTask => "Delete task",
+            Action::ToggleDone => "Toggle done",
+            Action::NextStatus => "Next status",
+            Action::PreviousStatus => "Previous status",
+            Action::RaisePriority => "Raise priority",
+            Action::LowerPriority => "Lower priority",
+            Action::SortByPriority => "Sort by priority",
+            Action::MoveTaskUp => "Move task up",
+            Action::MoveTaskDown => "Move task down",
+            Action::MoveToTab => "Move task to another tab",
+            Action::CopyToTab => "Copy task to another tab",
+            Action::TagTask => "Add or remove a #tag",
+            Action::Undo => "Undo",
+            Action::Redo => "Redo",
+            Action::Filter => "Filter current tab",
+            Action::ClearFilter => "Clear filter",
+            Action::GlobalSearch => "Search all tabs",
+            Action::ToggleTagSidebar => "Tag sidebar",
+            Action::ToggleDetails => "Toggle details pane",
+            Action::ShowQr => "Show link as QR code",
+            Action::ScrollDetailsDown => "Scroll details down",
+            Action::ScrollDetailsUp => "Scroll details up",
+            Action::PageDetailsDown => "Page details down",
+            Action::PageDetailsUp => "Page details up",
+            Action::TogglePrivacy => "Hide task text",
+            Action::ToggleDebug => "Debug overlay",
+            Action::CycleTheme => "Next theme",
+            Action::Down => "Select next task",
+            Action::Up => "Select previous task",
+            Action::Top => "Select first task",
+            Action::Bottom => "Select last task",
+            Action::PageDown => "Page down the task list, or the details pane when shown",
+            Action::PageUp => "Page up the task list, or the details pane when shown",
+            Action::CommandLine => "Command line",
+            Action::NextTab => "Next tab",
+            Action::PreviousTab => "Previous tab",
+            Action::RecentTabs => "Recent tabs",
+            Action::NewTab => "New tab",
+            Action::RenameTab => "Rename tab",
+            Action::DeleteTab => "Delete tab",
+            Action::SelectTab(_) => "Go to tab 1-9",
+            Action::Help => "This help",
+        }
+    }
+}
+
+// Satu tombol plus modifier, dinormalisasi supaya cocok dengan KeyEvent dari crossterm
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct KeyChord {
+    code: KeyCode,
+    modifiers: KeyModifiers,
+}
+
+impl KeyChord {
+    // Shift sudah tercermin di huruf besar/BackTab, jadi diabaikan di sini
+    fn new(code: KeyCode, modifiers: KeyModifiers) -> Self {
+        let modifiers = match code {
+            KeyCode::Char(_) | KeyCode::BackTab => modifiers - KeyModifiers::SHIFT,
+            _ => modifiers,
+        };
+        Self { code, modifiers }
+    }
+
+    // Format: "a", "T", "ctrl+b", "shift+tab", "f12", "space", "enter", ...
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let invalid = || format!("invalid key: {:?}", text);
+        let mut parts: Vec<&str> = text.split('+').collect();
+        // "+" sendiri atau "ctrl++" berarti tombol plus
+        if text.ends_with('+') {
+            parts.retain(|part| !part.is_empty());
+            parts.push("+");
+        }
+        let (key, modifier_names) = parts.split_last().ok_or_else(invalid)?;
+        let mut modifiers = KeyModifiers::NONE;
+        for name in modifier_names {
+            modifiers |= match name.to_lowercase().as_str() {
+                "ctrl" | "control" => KeyModifiers::CONTROL,
+                "alt" => KeyModifiers::ALT,
+                "shift" => KeyModifiers::SHIFT,
+                _ => return Err(invalid()),
+            };
+        }
+        let mut chars = key.chars();
+        let code = match (chars.next(), chars.next()) {
+            (Some(c), None) if modifiers.contains(KeyModifiers::SHIFT) => {
+                KeyCode::Char(c.to_ascii_uppercase())
+            }
+            (Some(c), None) => KeyCode::Char(c),
+            _ => match key.to_lowercase().as_str() {
+                "space" => KeyCode::Char(' '),
+                "enter" | "return" => KeyCode::Enter,
+                "esc" | "escape" => KeyCode::Esc,
+                "tab" if modifiers.contains(KeyModifiers::SHIFT) => KeyCode::BackTab,
+                "tab" => KeyCode::Tab,
+                "backtab" => KeyCode::BackTab,
+                "backspace" => KeyCode::Backspace,
+                "delete" | "del" => KeyCode::Delete,
+                "insert" => KeyCode::Insert,
+                "up" => KeyCode::Up,
+                "down" => KeyCode::Down,
+                "left" => KeyCode::Left,
+                "right" => KeyCode::Right,
+                "home" => KeyCode::Home,
+                "end" => KeyCode::End,
+                "pageup" => KeyCode::PageUp,
+                "pagedown" => KeyCode::PageDown,
+                name => match name.strip_prefix('f').map(str::parse::<u8>) {
+                    Some(Ok(number @ 1..=24)) => KeyCode::F(number),
+                    _ => return Err(invalid()),
+                },
+            },
+        };
+        Ok(Self::new(code, modifiers))
+    }
+}
+
+// Ditulis dengan format yang sama seperti di config
+impl fmt::Display for KeyChord {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (modifier, name) in [
+            (KeyModifiers::CONTROL, "ctrl+"),
+            (KeyModifiers::ALT, "alt+"),
+            (KeyModifiers::SHIFT, "shift+"),
+        ] {
+            if self.modifiers.contains(modifier) {
+                f.write_str(name)?;
+            }
+        }
+        match self.code {
+            KeyCode::Char(' ') => f.write_str("space"),
+            KeyCode::Char(c) => write!(f, "{}", c),
+            KeyCode::F(number) => write!(f, "f{}", number),
+            KeyCode::Enter => f.write_str("enter"),
+            KeyCode::Esc => f.write_str("esc"),
+            KeyCode::Tab => f.write_str("tab"),
+            KeyCode::BackTab => f.write_str("shift+tab"),
+            KeyCode::Backspace => f.write_str("backspace"),
+            KeyCode::Delete => f.write_str("delete"),
+            KeyCode::Insert => f.write_str("insert"),
+            KeyCode::Up => f.write_str("up"),
+            KeyCode::Down => f.write_str("down"),
+            KeyCode::Left => f.write_str("left"),
+            KeyCode::Right => f.write_str("right"),
+            KeyCode::Home => f.write_str("home"),
+            KeyCode::End => f.write_str("end"),
+            KeyCode::PageUp => f.write_str("pageup"),
+            KeyCode::PageDown => f.write_str("pagedown"),
+            code => write!(f, "{:?}", code),
+        }
+    }
+}
+
+impl From<KeyEvent> for KeyChord {
+    fn from(key: KeyEvent) -> Self {
+        Self::new(key.code, key.modifiers)
+    }
+}
+
+// Kebalikannya, untuk mengirim tombol dari skrip (misalnya di test)
+impl From<KeyChord> for KeyEvent {
+    fn from(chord: KeyChord) -> Self {
+        KeyEvent::new(chord.code, chord.modifiers)
+    }
+}
+
+// Isi bagian [keys] di config: nama aksi -> satu tombol atau daftar tombol
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(transparent)]
+pub struct KeysConfig(HashMap<String, KeyList>);
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+enum KeyList {
+    One(String),
+    Many(Vec<String>),
+}
+
+#[derive(Debug, Clone)]
+pub struct Keymap {
+    bindings: HashMap<KeyChord, Action>,
+    pub vim: bool,
+    vim_sequences: Vec<(Vec<KeyChord>, Action)>,
+}
+
+impl Default for Keymap {
+    fn default() -> Self {
+        let mut bindings: HashMap<KeyChord, Action> = DEFAULT_BINDINGS
+            .iter()
+            .map(|(key, action)| (KeyChord::parse(key).expect("valid default key"), *action))
+            .collect();
+        // 1-9 langsung ke tab ke-N
+        for (index, digit) in ('1'..='9').enumerate() {
+            let chord = KeyChord::new(KeyCode::Char(digit), KeyModifiers::NONE);
+            bindings.insert(chord, Action::SelectTab(index));
+        }
+        let vim_sequences = VIM_BINDINGS
+            .iter()
+            .map(|(keys, action)| {
+                let chords = keys
+                    .chars()
+                    .map(|c| KeyChord::new(KeyCode::Char(c), KeyModifiers::NONE))
+                    .collect();
+                (chords, *action)
+            })
+            .collect();
+        Self {
+            bindings,
+            vim: false,
+            vim_sequences,
+        }
+    }
+}
+
+impl Keymap {
+    // Aksi yang disebut di config mengganti semua tombol bawaannya
+    pub fn from_config(config: &KeysConfig) -> Result<Self, String> {
+        let mut keymap = Self::default();
+        for (name, keys) in &config.0 {
+            let action =
+                Action::from_name(name).ok_or_else(|| format!("unknown action: {:?}", name))?;
+            let keys = match keys {
+                KeyList::One(key) => std::slice::from_ref(key),
+                KeyList::Many(keys) => keys.as_slice(),
+            };
+            keymap.bindings.retain(|_, bound| *bound != action);
+            for key in keys {
+                keymap.bindings.insert(KeyChord::parse(key)?, action);
+            }
+        }
+        Ok(keymap)
+    }
+
+    // Tombol tiap aksi untuk popup bantuan, per kategori. Dibuat dari binding yang
+    // sedang aktif, jadi ikut berubah kalau config mengganti tombol.
+    pub fn help(&self) -> Vec<(Category, Vec<(String, &'static str)>)> {
+        let actions = ACTION_NAMES
+            .iter()
+            .map(|(_, action)| *action)
+            .chain([Action::SelectTab(0)]);
+        let mut entries: Vec<(Action, String)> = Vec::new();
+        for action in actions {
+            // Tab 1-9 digabung jadi satu baris
+            let targets: Vec<Action> = match action {
+                Action::SelectTab(_) => (0..9).map(Action::SelectTab).collect(),
+                action => vec![action],
+            };
+            let keys: Vec<String> = targets
+                .iter()
+                .flat_map(|&target| self.keys_for(target))
+                .collect();
+            let keys = keys.join(", ");
+            if !keys.is_empty() {
+                // Angka bawaan cukup ditulis sebagai rentang
+                let keys = if keys == "1, 2, 3, 4, 5, 6, 7, 8, 9" {
+                    "1-9".to_string()
+                } else {
+                    keys
+                };
+                entries.push((action, keys));
+            }
+        }
+        Category::ALL
+            .iter()
+            .map(|&category| {
+                let rows = entries
+                    .iter()
+                    .filter(|(action, _)| action.category() == category)
+                    .map(|(action, keys)| (keys.clone(), action.description()))
+                    .collect();
+                (category, rows)
+            })
+            .collect()
+    }
+
+    // Di mode vim, tombol pertama sebuah urutan menutupi binding biasa untuk tombol itu
+    fn keys_for(&self, action: Action) -> Vec<String> {
+        let shadowed = |chord: &KeyChord| {
+            self.vim
+                && self
+                    .vim_sequences
+                    .iter()
+                    .any(|(keys, _)| keys.first() == Some(chord))
+        };
+        let mut chords: Vec<&KeyChord> = self
+            .bindings
+            .iter()
+            .filter(|(chord, bound)| **bound == action && !shadowed(chord))
+            .map(|(chord, _)| chord)
+            .collect();
+        chords.sort_by_key(|chord| chord.to_string());
+        let mut keys: Vec<String> = chords.iter().map(|chord| chord.to_string()).collect();
+        if self.vim {
+            keys.extend(
+                self.vim_sequences
+                    .iter()
+                    .filter(|(_, bound)| *bound == action)
+                    .map(|(chords, _)| chords.iter().map(KeyChord::to_string).collect()),
+            );
+        }
+        keys
+    }
+
+    // `pending` menyimpan awal urutan vim yang belum lengkap di antara penekanan tombol
+    pub fn action(&self, pending: &mut Vec<KeyChord>, key: KeyEvent) -> Option<Action> {
+        let chord = KeyChord::from(key);
+        if self.vim {
+            pending.push(chord);
+            if let Some((_, action)) = self.vim_sequences.iter().find(|(keys, _)| keys == pending) {
+                pending.clear();
+                return Some(*action);
+            }
+            if self
+                .vim_sequences
+                .iter()
+                .any(|(keys, _)| keys.starts_with(pending))
+            {
+                return None;
+            }
+            // Urutan tidak dikenal: buang dan proses tombol terakhir sendirian
+            let started_sequence = pending.len() > 1;
+            pending.clear();
+            if started_sequence {
+                return self.action(pending, key);
+            }
+        }
+        self.bindings.get(&chord).copied()
+    }
+}
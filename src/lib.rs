@@ -0,0 +1,16 @@
+pub mod app;
+pub mod command;
+pub mod config;
+pub mod due;
+pub mod event;
+pub mod export;
+pub mod history;
+pub mod journal;
+pub mod keymap;
+pub mod notes;
+pub mod session;
+pub mod storage;
+pub mod theme;
+pub mod ui;
+
+pub use app::{App, InputMode, Priority, StatefulList, TabData, Task};
@@ -1,186 +1,573 @@
+use chrono::Local;
 use crossterm::{
-    event::{self, Event, KeyCode},
+    event::{DisableMouseCapture, EnableMouseCapture, KeyCode, KeyModifiers},
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
+use fauzyrustproject::{
+    config,
+    event::{handle_key, handle_mouse, AppEvent, Events},
+    journal::{self, Journal},
+    keymap::Keymap,
+    notes::NoteFile,
+    session::{self, Header, Recorder},
+    storage,
+    theme::Theme,
+    ui, App,
+};
 use ratatui::{
     backend::{Backend, CrosstermBackend},
-    layout::{Constraint, Direction, Layout},
-    style::{Color, Modifier, Style},
-    widgets::{Block, Borders, List, ListItem, ListState, Paragraph, Tabs},
-    Terminal,
+    layout::Rect,
+    Terminal, TerminalOptions, Viewport,
+};
+use std::{
+    error::Error,
+    io,
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::{Duration, Instant},
 };
-use std::{error::Error, io};
 
-// Struktur untuk daftar tugas dengan stateful
-struct StatefulList<T> {
-    state: ListState,
-    items: Vec<T>,
+// Interval tick default; sinyal terminasi juga diperiksa setiap tick
+const DEFAULT_TICK_RATE: Duration = Duration::from_millis(250);
+
+// Jarak antar frame selama animasi berjalan
+const ANIMATION_FRAME: Duration = Duration::from_millis(30);
+
+// Waktu loading sebelum layar progres ditampilkan
+const LOADING_DELAY: Duration = Duration::from_millis(100);
+
+// Tinggi default viewport untuk mode --inline
+const DEFAULT_INLINE_HEIGHT: u16 = 12;
+
+struct Args {
+    bench_data: Option<usize>,
+    debug: bool,
+    edit_in_place: bool,
+    inline: Option<u16>,
+    lock_after: Option<u64>, // Detik; Some(0) mematikan kunci dari config
+    data_file: Option<PathBuf>,
+    config_file: Option<PathBuf>,
+    record: Option<PathBuf>,
+    replay: Option<PathBuf>,
+    tick_rate: Duration,
 }
 
-impl<T> StatefulList<T> {
-    fn new(items: Vec<T>) -> Self {
-        Self {
-            state: ListState::default(),
-            items,
+fn parse_args() -> Result<Args, String> {
+    let mut args = Args {
+        bench_data: None,
+        debug: false,
+        edit_in_place: false,
+        inline: None,
+        lock_after: None,
+        data_file: None,
+        config_file: None,
+        record: None,
+        replay: None,
+        tick_rate: DEFAULT_TICK_RATE,
+    };
+    let mut iter = std::env::args().skip(1).peekable();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--bench-data" => {
+                let value = iter
+                    .next()
+                    .ok_or_else(|| "--bench-data requires a task count".to_string())?;
+                let count = value
+                    .parse()
+                    .map_err(|_| format!("invalid task count for --bench-data: {}", value))?;
+                args.bench_data = Some(count);
+            }
+            "--debug" => args.debug = true,
+            "--edit-in-place" => args.edit_in_place = true,
+            "--inline" => {
+                // Jumlah baris opsional, misalnya `--inline 15`
+                let height = match iter.peek().and_then(|value| value.parse().ok()) {
+                    Some(height) => {
+                        iter.next();
+                        height
+                    }
+                    None => DEFAULT_INLINE_HEIGHT,
+                };
+                args.inline = Some(height);
+            }
+            "--data-file" => {
+                let path = iter
+                    .next()
+                    .ok_or_else(|| "--data-file requires a path".to_string())?;
+                args.data_file = Some(PathBuf::from(path));
+            }
+            "--config" => {
+                let path = iter
+                    .next()
+                    .ok_or_else(|| "--config requires a path".to_string())?;
+                args.config_file = Some(PathBuf::from(path));
+            }
+            "--record" => {
+                let path = iter
+                    .next()
+                    .ok_or_else(|| "--record requires a path".to_string())?;
+                args.record = Some(PathBuf::from(path));
+            }
+            "--replay" => {
+                let path = iter
+                    .next()
+                    .ok_or_else(|| "--replay requires a path".to_string())?;
+                args.replay = Some(PathBuf::from(path));
+            }
+            "--tick-rate" => {
+                let value = iter
+                    .next()
+                    .ok_or_else(|| "--tick-rate requires milliseconds".to_string())?;
+                let millis: u64 = value
+                    .parse()
+                    .map_err(|_| format!("invalid milliseconds for --tick-rate: {}", value))?;
+                args.tick_rate = Duration::from_millis(millis.max(1));
+            }
+            "--lock-after" => {
+                let value = iter
+                    .next()
+                    .ok_or_else(|| "--lock-after requires a number of seconds".to_string())?;
+                let secs = value
+                    .parse()
+                    .map_err(|_| format!("invalid seconds for --lock-after: {}", value))?;
+                args.lock_after = Some(secs);
+            }
+            _ => return Err(format!("unknown argument: {}", arg)),
         }
     }
+    if args.record.is_some() && args.replay.is_some() {
+        return Err("--record and --replay cannot be used together".to_string());
+    }
+    Ok(args)
+}
 
-    fn next(&mut self) {
-        let i = match self.state.selected() {
-            Some(i) => {
-                if i >= self.items.len() - 1 {
-                    0
-                } else {
-                    i + 1
-                }
+fn main() -> Result<(), Box<dyn Error>> {
+    let args = parse_args()?;
+    let replay = match &args.replay {
+        Some(path) => {
+            let (header, records) = session::read(path)
+                .map_err(|err| format!("failed to read {}: {}", path.display(), err))?;
+            Some((path.clone(), header, records))
+        }
+        None => None,
+    };
+
+    // Data sintetis dari --bench-data dan salinan data di log sesi tidak pernah disimpan
+    let data_file = match (args.bench_data, &replay) {
+        (None, None) => args.data_file.clone().or_else(storage::default_path),
+        _ => None,
+    };
+    // Replay memakai config yang terekam di log sesi, bukan config di mesin ini
+    let config_source = match &replay {
+        Some((path, header, _)) => Some((path.clone(), header.config.clone())),
+        None => match args.config_file.clone().or_else(config::default_path) {
+            Some(path) => {
+                let contents = config::read(&path)
+                    .map_err(|err| format!("failed to load {}: {}", path.display(), err))?;
+                Some((path, contents))
+            }
+            None => None,
+        },
+    };
+    // Config dibaca sebelum terminal disiapkan supaya errornya tampil biasa;
+    // tanpa file config semua tombol dan warna memakai bawaan
+    let config = match &config_source {
+        Some((path, contents)) => {
+            let config = match contents {
+                Some(contents) => config::parse(contents)
+                    .map_err(|err| format!("failed to load {}: {}", path.display(), err))?,
+                None => config::Config::default(),
+            };
+            let keymap = Keymap::from_config(&config.keys)
+                .map_err(|err| format!("invalid [keys] in {}: {}", path.display(), err))?;
+            let theme = Theme::from_config(&config.theme)
+                .map_err(|err| format!("invalid [theme] in {}: {}", path.display(), err))?;
+            Some((config, keymap, theme))
+        }
+        None => None,
+    };
+    let theme = config
+        .as_ref()
+        .map_or_else(Theme::default_dark, |(_, _, theme)| *theme);
+    let animations = config
+        .as_ref()
+        .is_some_and(|(config, _, _)| config.animations);
+
+    let shutdown = Arc::new(AtomicBool::new(false));
+    register_shutdown_signals(&shutdown)?;
+
+    let inline = args.inline.is_some();
+    enter_terminal(inline)?;
+    let viewport = match args.inline {
+        Some(height) => Viewport::Inline(height),
+        None => Viewport::Fullscreen,
+    };
+    let backend = CrosstermBackend::new(io::stdout());
+    let mut terminal = Terminal::with_options(backend, TerminalOptions { viewport })?;
+
+    // Checksum isi file data yang dimuat, untuk mencocokkan journal
+    let mut base = 0;
+    let mut app = match (&replay, args.bench_data, &data_file) {
+        (Some((_, header, _)), _, _) => App::from_database(header.database.clone()),
+        (None, Some(count), _) => App::with_bench_data(count),
+        (None, None, Some(path)) => match load_data(&mut terminal, &theme, animations, path) {
+            Ok(Some((database, checksum))) => {
+                base = checksum;
+                let note_file = NoteFile::for_data(path, database.notes_file.as_deref());
+                let mut app = App::from_database(database);
+                app.note_file = Some(note_file);
+                app
             }
-            None => 0,
-        };
-        self.state.select(Some(i));
+            Ok(None) => {
+                let mut app = App::new();
+                app.note_file = Some(NoteFile::for_data(path, None));
+                app
+            }
+            Err(err) => {
+                leave_terminal(inline)?;
+                return Err(format!("failed to load {}: {}", path.display(), err).into());
+            }
+        },
+        (None, None, None) => App::new(),
+    };
+    if let Some(path) = &data_file {
+        if let Err(err) = recover(&mut app, path, base) {
+            leave_terminal(inline)?;
+            return Err(format!("failed to recover {}: {}", path.display(), err).into());
+        }
+    }
+    if let Some((config, keymap, theme)) = config {
+        app.keymap = keymap;
+        app.use_theme(theme);
+        app.keymap.vim = config.vim;
+        app.animations = config.animations;
+        app.lock_after = config.lock_after();
+        app.edit_in_place = config.edit_in_place;
+    }
+    app.debug.visible = args.debug;
+    // Flag di command line menimpa config
+    if args.edit_in_place {
+        app.edit_in_place = true;
+    }
+    if args.lock_after.is_some() {
+        app.lock_after = config::lock_timeout(args.lock_after);
+    }
+    let mut tick_rate = args.tick_rate;
+    // Opsi yang mengubah perilaku diambil dari log supaya replay berjalan sama persis
+    if let Some((_, header, _)) = &replay {
+        app.edit_in_place = header.edit_in_place;
+        app.lock_after = header.lock_after_secs.map(Duration::from_secs);
+        tick_rate = Duration::from_millis(header.tick_rate_ms.max(1));
     }
 
-    fn previous(&mut self) {
-        let i = match self.state.selected() {
-            Some(i) => {
-                if i == 0 {
-                    self.items.len() - 1
-                } else {
-                    i - 1
+    let size = terminal.size()?;
+    let mut recorder = match &args.record {
+        Some(path) => {
+            let started = Local::now();
+            // Log sesi membawa salinan data lengkap, termasuk teks catatan
+            let created = app.to_database_with_notes().and_then(|database| {
+                let header = Header {
+                    version: session::VERSION,
+                    started,
+                    size: (size.width, size.height),
+                    tick_rate_ms: tick_rate.as_millis() as u64,
+                    config: config_source.and_then(|(_, contents)| contents),
+                    edit_in_place: app.edit_in_place,
+                    lock_after_secs: app.lock_after.map(|timeout| timeout.as_secs()),
+                    database,
+                };
+                Recorder::create(path, &header)
+            });
+            match created {
+                Ok(recorder) => {
+                    app.clock = Some(started);
+                    Some(recorder)
+                }
+                Err(err) => {
+                    leave_terminal(inline)?;
+                    return Err(format!("failed to create {}: {}", path.display(), err).into());
                 }
             }
-            None => 0,
-        };
-        self.state.select(Some(i));
-    }
-}
-
-struct App {
-    tabs: Vec<String>,
-    active_tab: usize,
-    task_lists: Vec<StatefulList<String>>,
-    show_details: Vec<bool>,
-}
-
-impl App {
-    fn new() -> Self {
-        Self {
-            tabs: vec![
-                "Work".to_string(),
-                "Personal".to_string(),
-                "Hobbies".to_string(),
-            ],
-            active_tab: 0,
-            task_lists: vec![
-                StatefulList::new(vec![
-                    "Finish project report".to_string(),
-                    "Email manager".to_string(),
-                ]),
-                StatefulList::new(vec![
-                    "Buy groceries".to_string(),
-                    "Call family".to_string(),
-                ]),
-                StatefulList::new(vec![
-                    "Practice guitar".to_string(),
-                    "Read a book".to_string(),
-                ]),
-            ],
-            show_details: vec![false, false, false], // Semua detail tersembunyi
         }
+        None => None,
+    };
+    let mut events = match replay {
+        Some((path, header, records)) => {
+            app.message = Some(replay_message(&path, &header, records.len(), size));
+            let events = Events::replay(tick_rate, header.started, records);
+            app.clock = events.replay_clock();
+            events
+        }
+        None => Events::new(tick_rate),
+    };
+    let res = run_app(
+        &mut terminal,
+        &mut app,
+        &mut events,
+        &mut recorder,
+        inline,
+        &shutdown,
+    );
+
+    // Simpan dulu sebelum terminal dikembalikan: setelah SIGHUP (SSH putus) terminalnya
+    // sudah hilang dan memulihkannya bisa gagal, tapi perubahan tidak boleh ikut hilang
+    let saved = match &data_file {
+        Some(path) => save_data(&mut app, path),
+        None => Ok(()),
+    };
+    let restored = restore_terminal(&mut terminal, inline);
+
+    if let Err(err) = res {
+        eprintln!("{:?}", err);
     }
+    saved?;
+    restored?;
+    Ok(())
 }
 
-fn main() -> Result<(), Box<dyn Error>> {
-    enable_raw_mode()?;
-    let mut stdout = io::stdout();
-    execute!(stdout, EnterAlternateScreen)?;
-    let backend = CrosstermBackend::new(stdout);
-    let mut terminal = Terminal::new(backend)?;
+fn save_data(app: &mut App, path: &Path) -> Result<(), String> {
+    app.journal = None;
+    app.store_notes()
+        .and_then(|()| storage::save(path, &app.to_database()))
+        .map_err(|err| format!("failed to save {}: {}", path.display(), err))?;
+    // Data sudah aman di disk; journal hanya dibiarkan kalau simpan gagal
+    journal::remove(&Journal::path_for(path))
+        .map_err(|err| format!("failed to remove journal for {}: {}", path.display(), err))
+}
+
+// Semua langkah tetap dijalankan walau ada yang gagal; error pertama yang dilaporkan
+fn restore_terminal<B: Backend>(terminal: &mut Terminal<B>, inline: bool) -> io::Result<()> {
+    let mut result = Ok(());
+    if inline {
+        // Biarkan frame terakhir terlihat dan pindahkan prompt ke bawahnya
+        let area = terminal.get_frame().size();
+        result = result.and(terminal.set_cursor(0, area.bottom().saturating_sub(1)));
+    }
+    result = result.and(leave_terminal(inline));
+    result = result.and(terminal.show_cursor());
+    if inline {
+        println!();
+    }
+    result
+}
 
-    let app = App::new();
-    let res = run_app(&mut terminal, app);
+// Layar loading baru muncul kalau membaca file makan waktu lebih dari LOADING_DELAY,
+// jadi file kecil tidak menimbulkan kedipan. Dengan `animations`, spinner-nya ikut
+// berputar setiap kali progres digambar ulang.
+// Hasilnya data beserta checksum isi file, yang dibaca sekali saja
+fn load_data<B: Backend>(
+    terminal: &mut Terminal<B>,
+    theme: &Theme,
+    animations: bool,
+    path: &Path,
+) -> io::Result<Option<(storage::Database, u64)>> {
+    let Some(contents) = storage::read(path)? else {
+        return Ok(None);
+    };
+    let name = path
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let started = Instant::now();
+    let mut last_draw: Option<Instant> = None;
+    let database = storage::parse_with_progress(&contents, |read, total| {
+        if started.elapsed() < LOADING_DELAY
+            || last_draw.is_some_and(|last| last.elapsed() < ANIMATION_FRAME)
+        {
+            return;
+        }
+        last_draw = Some(Instant::now());
+        let ratio = read as f64 / total.max(1) as f64;
+        // Gagal menggambar tidak boleh menggagalkan loading
+        let elapsed = animations.then(|| started.elapsed());
+        let _ = terminal.draw(|f| ui::draw_loading(f, theme, &name, ratio, elapsed));
+    })?;
+    Ok(Some((database, journal::checksum(&contents))))
+}
 
-    disable_raw_mode()?;
-    execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
-    terminal.show_cursor()?;
+// Journal yang tersisa berarti proses sebelumnya mati sebelum menyimpan: terapkan
+// ulang isinya, simpan, lalu mulai journal baru untuk sesi ini. `base` adalah
+// checksum file data seperti yang dimuat (0 kalau belum ada).
+fn recover(app: &mut App, path: &Path, mut base: u64) -> io::Result<()> {
+    let journal_path = Journal::path_for(path);
+    if let Some(entries) = journal::read(&journal_path, base)? {
+        let count = entries.len();
+        app.replay(entries);
+        app.store_notes()?;
+        let contents = storage::to_bytes(&app.to_database())?;
+        storage::write(path, &contents)?;
+        base = journal::checksum(&contents);
+        journal::remove(&journal_path)?;
+        if count > 0 {
+            app.message = Some(format!(
+                "Recovered {} unsaved changes from the last session",
+                count
+            ));
+        }
+    }
+    app.journal = Some(Journal::create(&journal_path, base)?);
+    Ok(())
+}
 
-    if let Err(err) = res {
-        eprintln!("{:?}", err);
+// Ukuran terminal yang berbeda menggeser posisi klik dan jumlah baris per halaman
+fn replay_message(path: &Path, header: &Header, count: usize, size: Rect) -> String {
+    let mut message = format!(
+        "Replaying {} inputs from {}; keys are ignored until it ends",
+        count,
+        path.display()
+    );
+    let (width, height) = header.size;
+    if (width, height) != (size.width, size.height) {
+        message.push_str(&format!(
+            " (recorded at {}x{}, this terminal is {}x{})",
+            width, height, size.width, size.height
+        ));
+    }
+    message
+}
+
+// Gagal menulis log sesi tidak menghentikan aplikasi; perekaman saja yang berhenti
+fn record(recorder: &mut Option<Recorder>, app: &mut App, event: AppEvent) {
+    if let Some(active) = recorder {
+        match active.record(event) {
+            Ok(time) => app.clock = Some(time),
+            Err(err) => {
+                app.message = Some(format!("session log write failed: {}", err));
+                app.clock = None;
+                *recorder = None;
+            }
+        }
+    }
+}
+
+// SIGTERM/SIGHUP hanya menyalakan flag; loop event yang keluar dengan rapi
+#[cfg(unix)]
+fn register_shutdown_signals(shutdown: &Arc<AtomicBool>) -> io::Result<()> {
+    use signal_hook::consts::{SIGHUP, SIGTERM};
+    for signal in [SIGTERM, SIGHUP] {
+        signal_hook::flag::register(signal, Arc::clone(shutdown))?;
+    }
+    Ok(())
+}
+
+#[cfg(not(unix))]
+fn register_shutdown_signals(_shutdown: &Arc<AtomicBool>) -> io::Result<()> {
+    Ok(())
+}
+
+fn enter_terminal(inline: bool) -> io::Result<()> {
+    enable_raw_mode()?;
+    execute!(io::stdout(), EnableMouseCapture)?;
+    if !inline {
+        execute!(io::stdout(), EnterAlternateScreen)?;
     }
+    Ok(())
+}
+
+// Setiap langkah dicoba walau langkah sebelumnya gagal
+fn leave_terminal(inline: bool) -> io::Result<()> {
+    let raw = disable_raw_mode();
+    let mouse = execute!(io::stdout(), DisableMouseCapture);
+    let screen = if inline {
+        Ok(())
+    } else {
+        execute!(io::stdout(), LeaveAlternateScreen)
+    };
+    raw.and(mouse).and(screen)
+}
+
+// Ctrl+Z: kembalikan terminal, hentikan proses, lalu pulihkan saat `fg`
+#[cfg(unix)]
+fn suspend<B: Backend>(terminal: &mut Terminal<B>, inline: bool) -> io::Result<()> {
+    leave_terminal(inline)?;
+    terminal.show_cursor()?;
+    signal_hook::low_level::raise(signal_hook::consts::SIGTSTP)?;
+    enter_terminal(inline)?;
+    terminal.hide_cursor()?;
+    terminal.clear()
+}
 
+#[cfg(not(unix))]
+fn suspend<B: Backend>(_terminal: &mut Terminal<B>, _inline: bool) -> io::Result<()> {
     Ok(())
 }
 
-fn run_app<B: Backend>(terminal: &mut Terminal<B>, mut app: App) -> io::Result<()> {
+fn run_app<B: Backend>(
+    terminal: &mut Terminal<B>,
+    app: &mut App,
+    events: &mut Events,
+    recorder: &mut Option<Recorder>,
+    inline: bool,
+    shutdown: &AtomicBool,
+) -> io::Result<()> {
+    let tick_rate = events.tick_rate();
+    let mut replaying = events.is_replaying();
     loop {
-        terminal.draw(|f| {
-            let size = f.size();
-            let chunks = Layout::default()
-                .direction(Direction::Vertical)
-                .margin(1)
-                .constraints([Constraint::Length(3), Constraint::Min(1), Constraint::Length(3)])
-                .split(size);
-
-            // Render Tabs
-            let tabs: Vec<_> = app.tabs.iter().map(String::as_str).collect();
-            let tabs_widget = Tabs::new(tabs)
-                .block(Block::default().borders(Borders::ALL).title("Tabs"))
-                .highlight_style(Style::default().fg(Color::Yellow))
-                .select(app.active_tab);
-            f.render_widget(tabs_widget, chunks[0]);
-
-            // Render Task List for the Active Tab
-            let task_items: Vec<ListItem> = app.task_lists[app.active_tab]
-                .items
-                .iter()
-                .map(|task| ListItem::new(task.clone()).style(Style::default().fg(Color::White)))
-                .collect();
-            let task_list = List::new(task_items)
-                .block(Block::default().borders(Borders::ALL).title("Tasks"))
-                .highlight_style(
-                    Style::default()
-                        .bg(Color::Blue)
-                        .fg(Color::White)
-                        .add_modifier(Modifier::BOLD),
-                )
-                .highlight_symbol(">> ");
-            f.render_stateful_widget(
-                task_list,
-                chunks[1],
-                &mut app.task_lists[app.active_tab].state,
-            );
-
-            // Render Detail (Jika ditampilkan)
-            if app.show_details[app.active_tab] {
-                let detail = Paragraph::new("Detail for selected task...")
-                    .block(Block::default().borders(Borders::ALL).title("Details"))
-                    .style(Style::default().fg(Color::Gray));
-                f.render_widget(detail, chunks[1]);
-            }
-
-            // Render Instructions
-            let instructions = Paragraph::new(
-                "Use 1/2/3 to switch tabs, ↑/↓ to navigate, Enter to toggle details, q to quit.",
-            )
-            .style(Style::default().fg(Color::Gray));
-            f.render_widget(instructions, chunks[2]);
-        })?;
-
-        if let Event::Key(key) = event::read()? {
-            match key.code {
-                KeyCode::Char('q') => return Ok(()),
-                KeyCode::Char('1') => app.active_tab = 0,
-                KeyCode::Char('2') => app.active_tab = 1,
-                KeyCode::Char('3') => app.active_tab = 2,
-                KeyCode::Enter => {
-                    // Toggle detail visibility
-                    app.show_details[app.active_tab] = !app.show_details[app.active_tab];
+        let frame_start = Instant::now();
+        terminal.draw(|f| ui::draw(f, app))?;
+        app.debug.last_frame = frame_start.elapsed();
+        app.debug.frames += 1;
+
+        // Tick lebih rapat selama animasi supaya tiap frame tergambar
+        let animating = app.animation_progress().is_some();
+        events.set_tick_rate(if animating {
+            ANIMATION_FRAME.min(tick_rate)
+        } else {
+            tick_rate
+        });
+
+        let event = events.next_event()?;
+        if replaying {
+            app.clock = events.replay_clock();
+        }
+        match event {
+            AppEvent::Tick => {
+                if shutdown.load(Ordering::Relaxed) {
+                    return Ok(());
                 }
-                KeyCode::Down => app.task_lists[app.active_tab].next(),
-                KeyCode::Up => app.task_lists[app.active_tab].previous(),
-                _ => {}
+                app.on_tick();
+            }
+            AppEvent::Resize(_, _) => {
+                app.debug.record_event();
+                record(recorder, app, event);
+                // Sinkronkan buffer sekarang lalu redraw di iterasi berikutnya
+                terminal.autoresize()?;
+                app.on_resize(terminal.get_frame().size());
+            }
+            AppEvent::Key(key)
+                if !app.locked
+                    && key.code == KeyCode::Char('z')
+                    && key.modifiers.contains(KeyModifiers::CONTROL) =>
+            {
+                app.debug.record_event();
+                suspend(terminal, inline)?
             }
+            AppEvent::Key(key) => {
+                app.debug.record_event();
+                record(recorder, app, event);
+                handle_key(app, key);
+            }
+            AppEvent::Mouse(mouse) => {
+                app.debug.record_event();
+                record(recorder, app, event);
+                handle_mouse(app, mouse);
+            }
+        }
+        // Sesi rekaman biasanya diakhiri quit; replay berhenti di situ supaya state
+        // terakhir masih bisa diperiksa
+        if replaying && (app.should_quit || !events.is_replaying()) {
+            replaying = false;
+            app.should_quit = false;
+            app.clock = None;
+            events.stop_replay();
+            app.message = Some("Replay finished; nothing from this session is saved".to_string());
+        }
+        if app.should_quit {
+            return Ok(());
         }
     }
 }
-
@@ -0,0 +1,224 @@
+use serde::{Deserialize, Serialize};
+use std::{
+    cell::RefCell,
+    collections::BTreeMap,
+    fs::{self, File, OpenOptions},
+    io::{self, BufWriter, Read, Seek, SeekFrom, Write},
+    path::{Path, PathBuf},
+};
+
+// Catatan satu tugas. Tugas hanya memegang satu pointer (kosong kalau tanpa
+// catatan); catatan yang sudah disimpan cukup diingat posisinya di file catatan,
+// dan teksnya baru dibaca lewat NoteFile saat ditampilkan atau diedit.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Notes(Option<Box<Note>>);
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+enum Note {
+    // Belum masuk file catatan: baru diubah, dari file data versi lama, atau dari journal
+    Text(Box<str>),
+    Stored { at: u64, len: u64 },
+}
+
+impl Notes {
+    pub fn text(text: impl Into<String>) -> Self {
+        let text = text.into();
+        if text.is_empty() {
+            Self::default()
+        } else {
+            Self(Some(Box::new(Note::Text(text.into_boxed_str()))))
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        match self.0.as_deref() {
+            None => true,
+            Some(Note::Text(text)) => text.is_empty(),
+            Some(Note::Stored { len, .. }) => *len == 0,
+        }
+    }
+
+    // Teksnya kalau sedang ada di memori; catatan yang tersimpan lewat NoteFile::read
+    pub fn loaded(&self) -> Option<&str> {
+        match self.0.as_deref() {
+            None => Some(""),
+            Some(Note::Text(text)) => Some(text),
+            Some(Note::Stored { .. }) => None,
+        }
+    }
+
+    fn stored(&self) -> Option<(u64, u64)> {
+        match self.0.as_deref() {
+            Some(&Note::Stored { at, len }) => Some((at, len)),
+            _ => None,
+        }
+    }
+
+    // Byte di heap, untuk laporan memori
+    pub fn heap_size(&self) -> usize {
+        match self.0.as_deref() {
+            None => 0,
+            Some(Note::Text(text)) => std::mem::size_of::<Note>() + text.len(),
+            Some(Note::Stored { .. }) => std::mem::size_of::<Note>(),
+        }
+    }
+}
+
+// Sampah minimal sebelum file catatan dipadatkan
+const COMPACT_AFTER: u64 = 64 * 1024;
+
+// File catatan di samping file data (tasks.json -> tasks.json.notes): teks
+// catatan disambung begitu saja, tanpa pemisah. Hanya ditambah di akhir, jadi
+// posisi lama tetap berlaku untuk journal dan file data yang sudah ada. Saat
+// sampahnya banyak, catatan yang masih dipakai disalin ke file pasangannya
+// (.notes2), dan file data mencatat file mana yang dipakai.
+pub struct NoteFile {
+    path: PathBuf,
+    // Catatan terakhir yang dibaca; panel detail membacanya di setiap frame
+    last: RefCell<Option<((u64, u64), String)>>,
+}
+
+impl NoteFile {
+    // `name` dari Database::notes_file; None untuk file data baru atau versi lama
+    pub fn for_data(data_file: &Path, name: Option<&str>) -> Self {
+        let path = match name {
+            Some(name) => data_file.with_file_name(name),
+            None => {
+                let mut path = data_file.as_os_str().to_owned();
+                path.push(".notes");
+                PathBuf::from(path)
+            }
+        };
+        Self {
+            path,
+            last: RefCell::new(None),
+        }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn file_name(&self) -> String {
+        self.path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default()
+    }
+
+    // tasks.json.notes <-> tasks.json.notes2
+    fn other_path(&self) -> PathBuf {
+        let name = self.file_name();
+        let other = match name.strip_suffix('2') {
+            Some(name) => name.to_string(),
+            None => format!("{}2", name),
+        };
+        self.path.with_file_name(other)
+    }
+
+    pub fn read(&self, notes: &Notes) -> io::Result<String> {
+        let Some(position) = notes.stored() else {
+            return Ok(notes.loaded().unwrap_or_default().to_string());
+        };
+        if let Some((last, text)) = &*self.last.borrow() {
+            if *last == position {
+                return Ok(text.clone());
+            }
+        }
+        let text = self.read_at(position)?;
+        *self.last.borrow_mut() = Some((position, text.clone()));
+        Ok(text)
+    }
+
+    fn read_at(&self, (at, len): (u64, u64)) -> io::Result<String> {
+        let mut file = File::open(&self.path)?;
+        file.seek(SeekFrom::Start(at))?;
+        let mut bytes = Vec::new();
+        file.take(len).read_to_end(&mut bytes)?;
+        if bytes.len() as u64 != len {
+            return Err(io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                format!("{} is shorter than expected", self.path.display()),
+            ));
+        }
+        String::from_utf8(bytes).map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+    }
+
+    // Pindahkan semua catatan teks ke file, sebelum file data disimpan. Semua
+    // catatan yang tersimpan harus ikut di `notes`, karena pemadatan memindahkan
+    // posisinya; riwayat undo yang masih menunjuk file lama jadi tidak berlaku,
+    // jadi hanya dipanggil saat keluar atau saat memulihkan journal.
+    pub fn store<'a>(&mut self, notes: impl IntoIterator<Item = &'a mut Notes>) -> io::Result<()> {
+        let mut notes: Vec<&mut Notes> = notes
+            .into_iter()
+            .filter(|notes| notes.0.is_some())
+            .collect();
+        // File data di disk menunjuk `path`; pasangannya sisa pemadatan sebelumnya
+        match fs::remove_file(self.other_path()) {
+            Err(err) if err.kind() != io::ErrorKind::NotFound => return Err(err),
+            _ => {}
+        }
+        let size = match fs::metadata(&self.path) {
+            Ok(metadata) => metadata.len(),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => 0,
+            Err(err) => return Err(err),
+        };
+        // Tugas yang disalin berbagi posisi yang sama
+        let mut live: BTreeMap<(u64, u64), u64> = notes
+            .iter()
+            .filter_map(|notes| notes.stored())
+            .map(|position| (position, 0))
+            .collect();
+        let live_size: u64 = live.keys().map(|(_, len)| len).sum();
+        if size.saturating_sub(live_size) > COMPACT_AFTER.max(live_size) {
+            let other = self.other_path();
+            let mut out = BufWriter::new(File::create(&other)?);
+            let mut at = 0;
+            for (&position, moved) in live.iter_mut() {
+                out.write_all(self.read_at(position)?.as_bytes())?;
+                *moved = at;
+                at += position.1;
+            }
+            out.flush()?;
+            for notes in notes.iter_mut() {
+                if let Some(position @ (_, len)) = notes.stored() {
+                    **notes = Notes(Some(Box::new(Note::Stored {
+                        at: live[&position],
+                        len,
+                    })));
+                }
+            }
+            self.path = other;
+            *self.last.get_mut() = None;
+        }
+
+        // Catatan baru ditambahkan di akhir; file baru dibuat kalau memang ada isinya
+        notes.retain(|notes| notes.stored().is_none());
+        if notes.is_empty() {
+            return Ok(());
+        }
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        let mut at = file.metadata()?.len();
+        let mut out = BufWriter::new(&mut file);
+        for notes in notes {
+            let text = notes.loaded().unwrap_or_default();
+            let len = text.len() as u64;
+            out.write_all(text.as_bytes())?;
+            *notes = if len == 0 {
+                Notes::default()
+            } else {
+                Notes(Some(Box::new(Note::Stored { at, len })))
+            };
+            at += len;
+        }
+        out.flush()
+    }
+}
@@ -0,0 +1,114 @@
+use crate::{event::AppEvent, storage::Database};
+use chrono::{DateTime, Local};
+use serde::{Deserialize, Serialize};
+use std::{
+    fs::{self, File},
+    io::{self, BufRead, BufReader, Write},
+    path::Path,
+    time::{Duration, Instant},
+};
+
+// Naikkan kalau format log berubah; log versi lain ditolak saat replay
+pub const VERSION: u32 = 1;
+
+// Baris pertama log sesi: semua yang dibutuhkan untuk mengulang sesi di mesin lain
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Header {
+    pub version: u32,
+    pub started: DateTime<Local>,
+    pub size: (u16, u16),
+    pub tick_rate_ms: u64,
+    // Isi config.toml apa adanya, supaya tombol dan tema sama dengan milik pelapor
+    pub config: Option<String>,
+    pub edit_in_place: bool,
+    pub lock_after_secs: Option<u64>,
+    // Salinan data saat sesi dimulai; replay berjalan di atas salinan ini
+    pub database: Database,
+}
+
+// Satu input beserta waktunya sejak sesi dimulai
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Record {
+    pub at_ms: u64,
+    pub event: AppEvent,
+}
+
+impl Record {
+    pub fn at(&self) -> Duration {
+        Duration::from_millis(self.at_ms)
+    }
+}
+
+// Jam App untuk input pada `at_ms`, baik saat merekam maupun saat replay, supaya
+// tanggal dibuat dan "tomorrow" di quick entry menghasilkan nilai yang sama
+pub fn time_at(started: DateTime<Local>, at_ms: u64) -> DateTime<Local> {
+    started + chrono::Duration::milliseconds(at_ms as i64)
+}
+
+// Log sesi untuk laporan bug, satu baris JSON per entri. Hanya input yang dicatat;
+// tick tidak, karena replay membuat tick sendiri di sela input.
+pub struct Recorder {
+    file: File,
+    epoch: DateTime<Local>,
+    started: Instant,
+}
+
+impl Recorder {
+    pub fn create(path: &Path, header: &Header) -> io::Result<Self> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let mut recorder = Self {
+            file: File::create(path)?,
+            epoch: header.started,
+            started: Instant::now(),
+        };
+        recorder.write_line(header)?;
+        Ok(recorder)
+    }
+
+    // Langsung ke file seperti journal, jadi log tetap utuh kalau aplikasinya crash.
+    // Hasilnya jam yang dipakai App untuk input ini.
+    pub fn record(&mut self, event: AppEvent) -> io::Result<DateTime<Local>> {
+        let record = Record {
+            at_ms: self.started.elapsed().as_millis() as u64,
+            event,
+        };
+        self.write_line(&record)?;
+        Ok(time_at(self.epoch, record.at_ms))
+    }
+
+    fn write_line<T: Serialize>(&mut self, value: &T) -> io::Result<()> {
+        let mut line = serde_json::to_vec(value)
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+        line.push(b'\n');
+        self.file.write_all(&line)
+    }
+}
+
+// Baris terakhir yang terpotong (crash di tengah penulisan) diabaikan seperti di journal
+pub fn read(path: &Path) -> io::Result<(Header, Vec<Record>)> {
+    let mut lines = BufReader::new(File::open(path)?).lines();
+    let first = lines
+        .next()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "empty session log"))??;
+    let header: Header = serde_json::from_str(&first)
+        .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+    if header.version != VERSION {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!(
+                "session log version {} is not supported (expected {})",
+                header.version, VERSION
+            ),
+        ));
+    }
+    let mut records = Vec::new();
+    for line in lines {
+        match serde_json::from_str(&line?) {
+            Ok(record) => records.push(record),
+            Err(_) => break,
+        }
+    }
+    Ok((header, records))
+}
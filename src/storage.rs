@@ -0,0 +1,133 @@
+use crate::app::Task;
+use serde::{Deserialize, Deserializer, Serialize};
+use std::{
+    fs,
+    io::{self, BufReader, Read},
+    path::{Path, PathBuf},
+};
+
+// Satu tab beserta daftar tugasnya, seperti yang disimpan di disk
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TabRecord {
+    pub name: String,
+    // Daftar status khusus tab ini, misalnya ["Backlog", "In progress", "Done"]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub statuses: Vec<String>,
+    #[serde(deserialize_with = "deserialize_tasks")]
+    pub tasks: Vec<Task>,
+}
+
+// File versi lama menyimpan tugas sebagai string biasa
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum StoredTask {
+    Title(String),
+    Task(Task),
+}
+
+fn deserialize_tasks<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<Task>, D::Error> {
+    let stored = Vec::<StoredTask>::deserialize(deserializer)?;
+    Ok(stored
+        .into_iter()
+        .map(|task| match task {
+            StoredTask::Title(title) => Task::new(title),
+            StoredTask::Task(task) => task,
+        })
+        .collect())
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Database {
+    pub tabs: Vec<TabRecord>,
+    // Nama file catatan di folder yang sama (lihat notes::NoteFile); None kalau
+    // semua catatan ada di file ini sebagai teks
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub notes_file: Option<String>,
+}
+
+// ~/.local/share/firsttui/tasks.json di Linux, lokasi setara di OS lain
+pub fn default_path() -> Option<PathBuf> {
+    dirs::data_dir().map(|dir| dir.join("firsttui").join("tasks.json"))
+}
+
+// Ok(None) kalau file belum ada (misalnya saat pertama kali dijalankan)
+pub fn load(path: &Path) -> io::Result<Option<Database>> {
+    load_with_progress(path, |_, _| {})
+}
+
+// Sama seperti `load`, tapi `progress(terbaca, total)` dipanggil selama file
+// di-parse, supaya file besar bisa menampilkan layar loading
+pub fn load_with_progress(
+    path: &Path,
+    progress: impl FnMut(u64, u64),
+) -> io::Result<Option<Database>> {
+    match read(path)? {
+        Some(contents) => parse_with_progress(&contents, progress).map(Some),
+        None => Ok(None),
+    }
+}
+
+// Isi mentah file data; Ok(None) kalau file belum ada. Dipisah dari parse supaya
+// isi yang sama bisa dipakai untuk checksum journal tanpa membaca file dua kali.
+pub fn read(path: &Path) -> io::Result<Option<Vec<u8>>> {
+    match fs::read(path) {
+        Ok(contents) => Ok(Some(contents)),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(err) => Err(err),
+    }
+}
+
+pub fn parse_with_progress(
+    contents: &[u8],
+    progress: impl FnMut(u64, u64),
+) -> io::Result<Database> {
+    let reader = ProgressReader {
+        inner: contents,
+        read: 0,
+        total: contents.len() as u64,
+        progress,
+    };
+    serde_json::from_reader(BufReader::with_capacity(64 * 1024, reader)).map_err(|err| {
+        match err.io_error_kind() {
+            Some(kind) => io::Error::new(kind, err),
+            None => io::Error::new(io::ErrorKind::InvalidData, err),
+        }
+    })
+}
+
+// Menghitung byte yang sudah dibaca parser
+struct ProgressReader<R, F> {
+    inner: R,
+    read: u64,
+    total: u64,
+    progress: F,
+}
+
+impl<R: Read, F: FnMut(u64, u64)> Read for ProgressReader<R, F> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let count = self.inner.read(buf)?;
+        self.read += count as u64;
+        (self.progress)(self.read, self.total);
+        Ok(count)
+    }
+}
+
+pub fn save(path: &Path, database: &Database) -> io::Result<()> {
+    write(path, &to_bytes(database)?)
+}
+
+// Isi file seperti yang ditulis `save`
+pub fn to_bytes(database: &Database) -> io::Result<Vec<u8>> {
+    serde_json::to_vec_pretty(database)
+        .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+}
+
+pub fn write(path: &Path, contents: &[u8]) -> io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    // Tulis ke file sementara dulu supaya file lama tidak rusak kalau gagal
+    let tmp_path = path.with_extension("json.tmp");
+    fs::write(&tmp_path, contents)?;
+    fs::rename(&tmp_path, path)
+}
@@ -0,0 +1,265 @@
+use ratatui::{
+    layout::Alignment,
+    style::{Color, Modifier, Style},
+    widgets::{Block, BorderType, Borders, Padding},
+};
+use serde::Deserialize;
+use std::{collections::HashMap, str::FromStr};
+
+// Semua warna yang dipakai saat render
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Theme {
+    pub name: &'static str,
+    pub text: Color,
+    pub muted: Color, // Instruksi, panel detail, layar kunci
+    pub dim: Color,   // Bagian yang diredupkan saat edit di tempat
+    pub done: Color,
+    pub accent: Color, // Tab aktif, input, label
+    pub badge: Color,
+    pub selection_bg: Color,
+    pub selection_fg: Color,
+    pub danger: Color, // Lewat jatuh tempo, prioritas High, hapus tab
+    pub warning: Color,
+    pub success: Color,
+    pub panes: Panes,
+}
+
+// Bagian layar yang punya gaya border/judul sendiri
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Pane {
+    Tabs,
+    Tasks, // Termasuk hasil pencarian global yang menggantikannya
+    Details,
+    Footer, // Kotak input dan konfirmasi di bawah
+    Popup,  // Switcher tab, layar kunci, overlay debug
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PaneStyle {
+    pub border: Option<BorderType>, // None = tanpa border
+    pub title_alignment: Alignment,
+    pub padding: u16, // Spasi kiri-kanan di dalam border
+}
+
+impl Default for PaneStyle {
+    fn default() -> Self {
+        Self {
+            border: Some(BorderType::Plain),
+            title_alignment: Alignment::Left,
+            padding: 0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Panes {
+    pub tabs: PaneStyle,
+    pub tasks: PaneStyle,
+    pub details: PaneStyle,
+    pub footer: PaneStyle,
+    pub popup: PaneStyle,
+}
+
+impl Panes {
+    fn get_mut(&mut self, name: &str) -> Option<&mut PaneStyle> {
+        match name {
+            "tabs" => Some(&mut self.tabs),
+            "tasks" => Some(&mut self.tasks),
+            "details" => Some(&mut self.details),
+            "footer" => Some(&mut self.footer),
+            "popup" => Some(&mut self.popup),
+            _ => None,
+        }
+    }
+}
+
+impl Theme {
+    pub fn default_dark() -> Self {
+        Self {
+            name: "default",
+            text: Color::White,
+            muted: Color::Gray,
+            dim: Color::DarkGray,
+            done: Color::DarkGray,
+            accent: Color::Yellow,
+            badge: Color::Cyan,
+            selection_bg: Color::Blue,
+            selection_fg: Color::White,
+            danger: Color::Red,
+            warning: Color::Yellow,
+            success: Color::Green,
+            panes: Panes::default(),
+        }
+    }
+
+    // Untuk terminal berlatar terang
+    pub fn light() -> Self {
+        Self {
+            name: "light",
+            text: Color::Black,
+            muted: Color::DarkGray,
+            dim: Color::Gray,
+            done: Color::Gray,
+            accent: Color::Blue,
+            badge: Color::Magenta,
+            selection_bg: Color::LightBlue,
+            selection_fg: Color::Black,
+            danger: Color::Red,
+            warning: Color::Rgb(175, 95, 0),
+            success: Color::Green,
+            panes: Panes::default(),
+        }
+    }
+
+    pub fn high_contrast() -> Self {
+        Self {
+            name: "high-contrast",
+            text: Color::White,
+            muted: Color::White,
+            dim: Color::Gray,
+            done: Color::Gray,
+            accent: Color::LightYellow,
+            badge: Color::LightCyan,
+            selection_bg: Color::White,
+            selection_fg: Color::Black,
+            danger: Color::LightRed,
+            warning: Color::LightYellow,
+            success: Color::LightGreen,
+            panes: Panes::default(),
+        }
+    }
+
+    pub fn builtin() -> Vec<Theme> {
+        vec![Self::default_dark(), Self::light(), Self::high_contrast()]
+    }
+
+    // Bagian [theme] di config: `name` memilih tema bawaan, kunci lain mengganti warnanya
+    pub fn from_config(config: &ThemeConfig) -> Result<Self, String> {
+        let name = config.name.as_deref().unwrap_or("default");
+        let mut theme = Self::builtin()
+            .into_iter()
+            .find(|theme| theme.name == name)
+            .ok_or_else(|| format!("unknown theme: {:?}", name))?;
+        for (key, value) in &config.colors {
+            let color =
+                Color::from_str(value).map_err(|_| format!("invalid color: {:?}", value))?;
+            let slot = match key.as_str() {
+                "text" => &mut theme.text,
+                "muted" => &mut theme.muted,
+                "dim" => &mut theme.dim,
+                "done" => &mut theme.done,
+                "accent" => &mut theme.accent,
+                "badge" => &mut theme.badge,
+                "selection_bg" => &mut theme.selection_bg,
+                "selection_fg" => &mut theme.selection_fg,
+                "danger" => &mut theme.danger,
+                "warning" => &mut theme.warning,
+                "success" => &mut theme.success,
+                _ => return Err(format!("unknown theme color: {:?}", key)),
+            };
+            *slot = color;
+        }
+        // [theme.panes.all] dulu, lalu pane tertentu menimpanya
+        let mut names: Vec<&String> = config.panes.keys().collect();
+        names.sort_by_key(|name| name.as_str() != "all");
+        for name in names {
+            let pane = &config.panes[name];
+            if name == "all" {
+                for style in [
+                    &mut theme.panes.tabs,
+                    &mut theme.panes.tasks,
+                    &mut theme.panes.details,
+                    &mut theme.panes.footer,
+                    &mut theme.panes.popup,
+                ] {
+                    pane.apply(style)?;
+                }
+            } else {
+                let style = theme
+                    .panes
+                    .get_mut(name)
+                    .ok_or_else(|| format!("unknown pane: {:?}", name))?;
+                pane.apply(style)?;
+            }
+        }
+        if !config.colors.is_empty() || !config.panes.is_empty() {
+            theme.name = "custom";
+        }
+        Ok(theme)
+    }
+
+    // Block dengan border, perataan judul dan padding sesuai pane
+    pub fn block(&self, pane: Pane) -> Block<'static> {
+        let style = match pane {
+            Pane::Tabs => self.panes.tabs,
+            Pane::Tasks => self.panes.tasks,
+            Pane::Details => self.panes.details,
+            Pane::Footer => self.panes.footer,
+            Pane::Popup => self.panes.popup,
+        };
+        let block = Block::default()
+            .title_alignment(style.title_alignment)
+            .padding(Padding::horizontal(style.padding));
+        match style.border {
+            Some(border_type) => block.borders(Borders::ALL).border_type(border_type),
+            None => block,
+        }
+    }
+
+    pub fn fg(&self, color: Color) -> Style {
+        Style::default().fg(color)
+    }
+
+    // Baris terpilih di semua daftar
+    pub fn selection(&self) -> Style {
+        Style::default()
+            .bg(self.selection_bg)
+            .fg(self.selection_fg)
+            .add_modifier(Modifier::BOLD)
+    }
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct ThemeConfig {
+    pub name: Option<String>,
+    // [theme.panes.<pane>] dengan pane: all, tabs, tasks, details, footer, popup
+    #[serde(default)]
+    pub panes: HashMap<String, PaneConfig>,
+    #[serde(flatten)]
+    pub colors: HashMap<String, String>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PaneConfig {
+    pub border: Option<String>,      // plain, rounded, double, thick, none
+    pub title_align: Option<String>, // left, center, right
+    pub padding: Option<u16>,
+}
+
+impl PaneConfig {
+    fn apply(&self, style: &mut PaneStyle) -> Result<(), String> {
+        if let Some(border) = &self.border {
+            style.border = match border.as_str() {
+                "plain" => Some(BorderType::Plain),
+                "rounded" => Some(BorderType::Rounded),
+                "double" => Some(BorderType::Double),
+                "thick" => Some(BorderType::Thick),
+                "none" => None,
+                _ => return Err(format!("invalid border: {:?}", border)),
+            };
+        }
+        if let Some(align) = &self.title_align {
+            style.title_alignment = match align.as_str() {
+                "left" => Alignment::Left,
+                "center" => Alignment::Center,
+                "right" => Alignment::Right,
+                _ => return Err(format!("invalid title_align: {:?}", align)),
+            };
+        }
+        if let Some(padding) = self.padding {
+            style.padding = padding;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,937 @@
+use crate::{
+    app::{tag_name, Animation, App, DetailsScroll, InputMode, Priority},
+    command,
+    theme::{Pane, Theme},
+};
+use chrono::NaiveDate;
+use qrcode::{render::unicode::Dense1x2, QrCode};
+use ratatui::{
+    backend::Backend,
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Gauge, List, ListItem, ListState, Paragraph, Tabs, Wrap},
+    Frame,
+};
+use std::time::Duration;
+
+// Pengganti teks tugas saat mode privasi aktif
+const REDACTED_TEXT: &str = "••••••••";
+const TAG_SIDEBAR_WIDTH: u16 = 22;
+
+pub fn draw<B: Backend>(f: &mut Frame<B>, app: &mut App) {
+    let theme = *app.theme();
+    if app.locked {
+        render_lock_screen(f, &theme);
+        return;
+    }
+    let chunks = main_chunks(f.size());
+
+    // Sidebar tag di paling kiri, daftar dan detail berbagi sisanya
+    let main_area = if app.show_tags {
+        let panes = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Length(TAG_SIDEBAR_WIDTH), Constraint::Min(0)])
+            .split(chunks[1]);
+        render_tag_sidebar(f, app, &theme, panes[0]);
+        panes[1]
+    } else {
+        chunks[1]
+    };
+
+    // Panel detail tampil di samping daftar, bukan menimpanya
+    let (list_area, details_area) = if app.current_tab().show_details {
+        let panes = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+            .split(main_area);
+        (panes[0], Some(panes[1]))
+    } else {
+        (main_area, None)
+    };
+
+    // Setelah ganti tab, daftar tugas masuk dari kanan
+    let list_area = match app.animation_progress() {
+        Some((Animation::TabSlide, progress)) => {
+            let shift = ((1.0 - progress) * list_area.width as f32 / 4.0) as u16;
+            Rect {
+                x: list_area.x + shift,
+                width: list_area.width - shift,
+                ..list_area
+            }
+        }
+        _ => list_area,
+    };
+
+    // Edit langsung di baris daftar; bagian lain diredupkan
+    let editing_row = match app.input_mode {
+        InputMode::Editing(index) if app.edit_in_place => Some(index),
+        _ => None,
+    };
+    let dimmed = theme.fg(theme.dim);
+
+    // Render Tabs
+    let tabs: Vec<_> = app.tabs.iter().map(|tab| tab.name.as_str()).collect();
+    let mut tabs_widget = Tabs::new(tabs)
+        .block(theme.block(Pane::Tabs).title("Tabs"))
+        .style(theme.fg(theme.text))
+        .highlight_style(theme.fg(theme.accent))
+        .select(app.active_tab);
+    if editing_row.is_some() {
+        tabs_widget = tabs_widget.style(dimmed).highlight_style(dimmed);
+    }
+    f.render_widget(tabs_widget, chunks[0]);
+    // Tiap judul tab diberi satu spasi di kiri-kanan, lalu pemisah satu kolom
+    let tabs_inner = theme.block(Pane::Tabs).inner(chunks[0]);
+    let mut x = tabs_inner.x;
+    app.hit_areas.tabs = app
+        .tabs
+        .iter()
+        .map(|tab| {
+            let width = Span::raw(tab.name.as_str()).width() as u16 + 2;
+            let area = Rect::new(x, tabs_inner.y, width, 1).intersection(tabs_inner);
+            x = x.saturating_add(width + 1);
+            area
+        })
+        .collect();
+
+    // Render Task List for the Active Tab
+    let today = app.now().date_naive();
+    // Hanya tugas yang cocok dengan filter; index tetap index asli
+    let tasks_block = theme.block(Pane::Tasks);
+    let list_inner = tasks_block.inner(list_area);
+    let height = list_inner.height as usize;
+    let tab = app.current_tab_mut();
+    let visible = tab.visible_indices();
+    let selected = tab.list.state.selected();
+    let view = if !tab.is_filtered() {
+        &mut tab.list.state
+    } else {
+        // Terjemahkan seleksi asli ke posisi di daftar hasil filter
+        let position = selected.and_then(|selected| visible.binary_search(&selected).ok());
+        tab.filter_view.select(position);
+        &mut tab.filter_view
+    };
+    let position = view.selected();
+    let offset = list_offset(view.offset(), position, visible.len(), height);
+    *view.offset_mut() = offset;
+    // ListItem hanya dibuat untuk baris yang muat di layar, supaya biaya satu frame
+    // tidak ikut jumlah tugas
+    let window = &visible[offset..(offset + height).min(visible.len())];
+    let statuses = &app.current_tab().statuses;
+    let task_items: Vec<ListItem> = window
+        .iter()
+        .map(|&index| (index, &app.current_tab().list.items[index]))
+        .map(|(index, task)| {
+            let text = if editing_row == Some(index) {
+                vec![Span::raw(editing_text(app).to_string())]
+            } else if app.privacy_mode {
+                vec![Span::raw(REDACTED_TEXT)]
+            } else {
+                title_spans(&theme, &task.title)
+            };
+            let style = if editing_row == Some(index) {
+                theme.fg(theme.accent)
+            } else if editing_row.is_some() {
+                dimmed
+            } else if task.done {
+                theme.fg(theme.done).add_modifier(Modifier::CROSSED_OUT)
+            } else {
+                theme.fg(theme.text)
+            };
+            let mut spans = Vec::new();
+            if !task.priority.is_none() {
+                let marker = priority_marker(task.priority);
+                spans.push(Span::styled(marker, priority_style(&theme, task.priority)));
+            }
+            if !statuses.is_empty() {
+                // Badge status di depan judul
+                let badge = format!("[{}] ", statuses[task.status_index(statuses)]);
+                spans.push(Span::styled(badge, theme.fg(theme.badge)));
+            }
+            spans.extend(text);
+            if let (Some(due), None) = (task.due, editing_row) {
+                spans.push(Span::styled(
+                    format!("  due {}", due.format("%a %d %b")),
+                    due_style(&theme, task.done, due, today),
+                ));
+            }
+            ListItem::new(Line::from(spans)).style(style)
+        })
+        .collect();
+    let tasks_title = if app.privacy_mode {
+        format!("Tasks ({} hidden)", app.current_tab().list.items.len())
+    } else if app.current_tab().is_filtered() {
+        let tab = app.current_tab();
+        let mut filters = Vec::new();
+        if let Some(tag) = &tab.tag_filter {
+            filters.push(format!("#{}", tag));
+        }
+        if !tab.filter.is_empty() {
+            filters.push(format!("filter: {}", tab.filter));
+        }
+        format!(
+            "Tasks ({}, {}/{})",
+            filters.join(", "),
+            visible.len(),
+            tab.list.items.len()
+        )
+    } else {
+        "Tasks".to_string()
+    };
+    let task_list = List::new(task_items)
+        .block(tasks_block.title(tasks_title))
+        .highlight_style(theme.selection())
+        .highlight_symbol(">> ");
+    // Seleksi relatif terhadap potongan yang digambar; offset sudah dihitung di atas
+    let mut state = ListState::default().with_selected(position.map(|position| position - offset));
+    f.render_stateful_widget(task_list, list_area, &mut state);
+    render_scrollbar(f, &theme, list_area, offset, visible.len());
+    app.hit_areas.list = list_inner;
+    app.hit_areas.list_offset = offset;
+    app.hit_areas.details = details_area;
+    if let Some(index) = editing_row {
+        // Kursor di akhir teks: ">> " + prioritas + badge status + input
+        let tab = app.current_tab();
+        let task = &tab.list.items[index];
+        let mut prefix = priority_marker(task.priority).len();
+        if !tab.statuses.is_empty() {
+            let status = &tab.statuses[task.status_index(&tab.statuses)];
+            prefix += Span::raw(format!("[{}] ", status)).width();
+        }
+        let row = window.iter().position(|&i| i == index).unwrap_or(0) as u16;
+        let cursor_x = list_inner.x + 3 + (prefix + Span::raw(editing_text(app)).width()) as u16;
+        f.set_cursor(
+            cursor_x.min(list_inner.right().saturating_sub(1)),
+            (list_inner.y + row).min(list_inner.bottom().saturating_sub(1)),
+        );
+    }
+
+    // Render Detail (Jika ditampilkan)
+    if let Some(area) = details_area {
+        let task = app
+            .current_tab()
+            .list
+            .state
+            .selected()
+            .map(|index| (app.active_tab, index));
+        if app.details.task != task {
+            app.details = DetailsScroll {
+                task,
+                ..DetailsScroll::default()
+            };
+        }
+        let text = details_text(app, &theme);
+        // Jaga supaya scroll tidak melewati baris terakhir
+        let inner = theme.block(Pane::Details).inner(area);
+        let page = inner.height;
+        let total = wrapped_height(&text, inner.width);
+        app.details.page = page;
+        app.details.offset = app.details.offset.min(total.saturating_sub(page));
+        let offset = app.details.offset;
+        let title = if total > page {
+            format!(
+                "Details {}-{}/{}",
+                offset + 1,
+                (offset + page).min(total),
+                total
+            )
+        } else {
+            "Details".to_string()
+        };
+        let detail = Paragraph::new(text)
+            .block(theme.block(Pane::Details).title(title))
+            .style(if editing_row.is_some() {
+                dimmed
+            } else {
+                theme.fg(theme.muted)
+            })
+            .wrap(Wrap { trim: false })
+            .scroll((offset, 0));
+        f.render_widget(detail, area);
+    }
+
+    match app.input_mode {
+        InputMode::Normal if app.message.is_some() => {
+            // Hasil perintah `:` menggantikan instruksi sampai tombol berikutnya
+            let message = Paragraph::new(app.message.as_deref().unwrap_or_default())
+                .style(theme.fg(theme.accent))
+                .wrap(Wrap { trim: true });
+            f.render_widget(message, chunks[2]);
+        }
+        InputMode::Normal => {
+            // Render Instructions
+            let instructions = Paragraph::new(
+                "↑/↓ move, Tab/1-9 tabs, a/e/d add/edit/delete, Space done, / filter, \
+                 Ctrl+F search, u undo, Enter details, : command, ? all keys, q quit.",
+            )
+            .style(theme.fg(theme.muted))
+            .wrap(Wrap { trim: true });
+            f.render_widget(instructions, chunks[2]);
+        }
+        InputMode::ConfirmDeleteTab => {
+            let prompt = format!(
+                "Delete tab \"{}\" and its {} tasks? (y/n)",
+                app.current_tab().name,
+                app.current_tab().list.items.len()
+            );
+            let confirm = Paragraph::new(prompt)
+                .block(theme.block(Pane::Footer).title("Delete tab"))
+                .style(theme.fg(theme.danger));
+            f.render_widget(confirm, chunks[2]);
+        }
+        InputMode::SwitchingTab(cursor) => render_tab_switcher(f, app, &theme, cursor),
+        InputMode::PickingTab { copy, cursor } => render_tab_picker(f, app, &theme, copy, cursor),
+        InputMode::ShowingQr => render_qr_popup(f, app),
+        InputMode::ShowingHelp(offset) => render_help_popup(f, app, &theme, offset),
+        InputMode::Editing(_) if app.edit_in_place => {
+            let hint = Paragraph::new("Editing in place: Enter to save, Esc to cancel.")
+                .style(theme.fg(theme.accent));
+            f.render_widget(hint, chunks[2]);
+        }
+        InputMode::TagSidebar(_) => {
+            let hint = Paragraph::new(
+                "Tags: ↑/↓ move, Enter filter by tag, Esc back to tasks, t hide sidebar.",
+            )
+            .style(theme.fg(theme.accent))
+            .wrap(Wrap { trim: true });
+            f.render_widget(hint, chunks[2]);
+        }
+        InputMode::Adding
+        | InputMode::Editing(_)
+        | InputMode::EditingNotes(_)
+        | InputMode::Tagging(_)
+        | InputMode::NewTab
+        | InputMode::RenamingTab
+        | InputMode::Filtering
+        | InputMode::GlobalSearch(_)
+        | InputMode::Command => {
+            // Render Input (Enter simpan, Esc batal)
+            let title = match app.input_mode {
+                InputMode::Adding => "New task (Enter to add, Esc to cancel)",
+                InputMode::EditingNotes(_) => "Notes (Enter to save, Esc to cancel)",
+                InputMode::Tagging(_) => "Tag (Enter to add, or remove if present; Esc to cancel)",
+                InputMode::NewTab => "New tab name (Enter to create, Esc to cancel)",
+                InputMode::RenamingTab => "Rename tab (Enter to save, Esc to cancel)",
+                InputMode::Filtering => "Filter (Enter to keep, Esc to clear)",
+                InputMode::GlobalSearch(_) => "Search all tabs (Enter to jump, Esc to cancel)",
+                InputMode::Command => ": (Tab to complete, Enter to run, Esc to cancel)",
+                _ => "Edit task (Enter to save, Esc to cancel)",
+            };
+            let block = theme.block(Pane::Footer).title(title);
+            let inner = block.inner(chunks[2]);
+            let input = Paragraph::new(app.input.as_str())
+                .block(block)
+                .style(theme.fg(theme.accent));
+            f.render_widget(input, chunks[2]);
+            let cursor_x = inner.x + Span::raw(app.input.as_str()).width() as u16;
+            f.set_cursor(cursor_x.min(inner.right().saturating_sub(1)), inner.y);
+        }
+    }
+
+    if let InputMode::GlobalSearch(cursor) = app.input_mode {
+        render_search_results(f, app, &theme, cursor, chunks[1]);
+    }
+    if app.input_mode == InputMode::Command {
+        render_command_suggestions(f, app, &theme, chunks[1]);
+    }
+
+    // Render Debug Overlay
+    if app.debug.visible {
+        render_debug_overlay(f, app, &theme);
+    }
+}
+
+// Isi baris yang sedang diedit langsung; disamarkan juga di mode privasi
+fn editing_text(app: &App) -> &str {
+    if app.privacy_mode {
+        REDACTED_TEXT
+    } else {
+        &app.input
+    }
+}
+
+// Scrollbar di border kanan daftar, hanya kalau tugas tidak muat satu layar
+fn render_scrollbar<B: Backend>(
+    f: &mut Frame<B>,
+    theme: &Theme,
+    area: Rect,
+    offset: usize,
+    total: usize,
+) {
+    let inner = theme.block(Pane::Tasks).inner(area);
+    let height = inner.height as usize;
+    if total <= height || height == 0 || area.width == 0 {
+        return;
+    }
+    // Panjang thumb sebanding dengan bagian yang terlihat, minimal satu baris
+    let thumb = (height * height / total).max(1);
+    let max_offset = total - height;
+    let start = offset.min(max_offset) * (height - thumb) / max_offset;
+    let lines: Vec<Line> = (0..height)
+        .map(|row| {
+            if (start..start + thumb).contains(&row) {
+                Line::from(Span::styled("█", theme.fg(theme.accent)))
+            } else {
+                Line::from(Span::styled("│", theme.fg(theme.dim)))
+            }
+        })
+        .collect();
+    let track = Rect::new(area.right() - 1, inner.y, 1, inner.height);
+    f.render_widget(Paragraph::new(lines), track);
+}
+
+// Judul tugas dengan kata #tag diberi warna badge
+fn title_spans(theme: &Theme, title: &str) -> Vec<Span<'static>> {
+    title
+        .split_inclusive(' ')
+        .map(|word| match tag_name(word.trim_end()) {
+            Some(tag) => Span::styled(word.to_string(), tag_style(theme, tag)),
+            None => Span::raw(word.to_string()),
+        })
+        .collect()
+}
+
+// Warna tetap per tag, dipilih dari nama tag supaya sama di setiap tab
+fn tag_style(theme: &Theme, tag: &str) -> Style {
+    let colors = [theme.badge, theme.success, theme.warning, theme.accent];
+    let hash = tag.to_lowercase().bytes().fold(0usize, |hash, byte| {
+        hash.wrapping_mul(31).wrapping_add(byte as usize)
+    });
+    theme
+        .fg(colors[hash % colors.len()])
+        .add_modifier(Modifier::BOLD)
+}
+
+// Daftar tag di tab aktif beserta jumlah tugasnya; baris pertama melepas saringan
+fn render_tag_sidebar<B: Backend>(f: &mut Frame<B>, app: &mut App, theme: &Theme, area: Rect) {
+    let counts = app.current_tab_mut().tag_counts().to_vec();
+    let tab = app.current_tab();
+    let mut items = vec![ListItem::new(Line::from(vec![
+        Span::raw("All "),
+        Span::styled(format!("({})", tab.list.items.len()), theme.fg(theme.muted)),
+    ]))];
+    // Nama tag berasal dari judul tugas, jadi ikut disamarkan di mode privasi
+    items.extend(counts.iter().map(|(tag, count)| {
+        let name = if app.privacy_mode {
+            Span::styled(format!("{} ", REDACTED_TEXT), theme.fg(theme.muted))
+        } else {
+            Span::styled(format!("#{} ", tag), tag_style(theme, tag))
+        };
+        ListItem::new(Line::from(vec![
+            name,
+            Span::styled(format!("({})", count), theme.fg(theme.muted)),
+        ]))
+    }));
+    let active = tab
+        .tag_filter
+        .as_ref()
+        .and_then(|tag| counts.iter().position(|(name, _)| name == tag))
+        .map_or(0, |position| position + 1);
+    let (selected, highlight) = match app.input_mode {
+        InputMode::TagSidebar(cursor) => (cursor, theme.selection()),
+        _ => (active, theme.fg(theme.accent)),
+    };
+    let list = List::new(items)
+        .block(theme.block(Pane::Tasks).title("Tags"))
+        .highlight_style(highlight)
+        .highlight_symbol("> ");
+    let mut state = ListState::default();
+    state.select(Some(selected));
+    f.render_stateful_widget(list, area, &mut state);
+}
+
+// Isi panel detail untuk tugas yang sedang dipilih
+fn details_text(app: &App, theme: &Theme) -> Vec<Line<'static>> {
+    let Some(task) = app.selected_task() else {
+        return vec![Line::from("No task selected.")];
+    };
+    let statuses = &app.current_tab().statuses;
+    let (title, notes) = if app.privacy_mode {
+        (REDACTED_TEXT.to_string(), REDACTED_TEXT.to_string())
+    } else {
+        let notes = match app.note_text(&task.notes) {
+            Ok(notes) if notes.is_empty() => "(no notes)".to_string(),
+            Ok(notes) => notes,
+            Err(err) => format!("(notes unavailable: {})", err),
+        };
+        (task.title.clone(), notes)
+    };
+    let created = match task.created {
+        Some(created) => created.format("%Y-%m-%d %H:%M").to_string(),
+        None => "unknown".to_string(),
+    };
+    let due = match task.due {
+        Some(due) => Span::styled(
+            due.format("%Y-%m-%d").to_string(),
+            due_style(theme, task.done, due, app.now().date_naive()),
+        ),
+        None => Span::raw("none"),
+    };
+    let label = theme.fg(theme.accent);
+    let mut lines = vec![
+        Line::from(Span::styled(
+            title,
+            Style::default().add_modifier(Modifier::BOLD),
+        )),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("Status:  ", label),
+            Span::raw(task.status_label(statuses)),
+        ]),
+        Line::from(vec![Span::styled("Created: ", label), Span::raw(created)]),
+        Line::from(vec![Span::styled("Due:     ", label), due]),
+        Line::from(vec![
+            Span::styled("Priority: ", label),
+            Span::styled(task.priority.label(), priority_style(theme, task.priority)),
+        ]),
+        Line::from(""),
+        Line::from(Span::styled("Notes:", label)),
+    ];
+    lines.extend(notes.lines().map(|line| Line::from(line.to_string())));
+    lines
+}
+
+// Perkiraan jumlah baris setelah word wrap, untuk batas scroll panel detail
+fn wrapped_height(lines: &[Line], width: u16) -> u16 {
+    let width = width.max(1) as usize;
+    let rows: usize = lines
+        .iter()
+        .map(|line| {
+            let text: String = line
+                .spans
+                .iter()
+                .map(|span| span.content.as_ref())
+                .collect();
+            let mut rows = 1;
+            let mut used = 0;
+            for word in text.split(' ') {
+                let word_width = Span::raw(word).width();
+                let needed = if used == 0 {
+                    word_width
+                } else {
+                    used + 1 + word_width
+                };
+                if needed <= width {
+                    used = needed;
+                    continue;
+                }
+                if used > 0 {
+                    rows += 1;
+                }
+                // Kata yang lebih panjang dari lebar panel dipotong per baris
+                rows += word_width.saturating_sub(1) / width;
+                used = word_width - word_width.saturating_sub(1) / width * width;
+            }
+            rows
+        })
+        .sum();
+    rows.min(u16::MAX as usize) as u16
+}
+
+// Penanda di depan judul: "!!! " untuk High sampai "! " untuk Low
+fn priority_marker(priority: Priority) -> &'static str {
+    match priority {
+        Priority::High => "!!! ",
+        Priority::Medium => "!! ",
+        Priority::Low => "! ",
+        Priority::None => "",
+    }
+}
+
+fn priority_style(theme: &Theme, priority: Priority) -> Style {
+    match priority {
+        Priority::High => theme.fg(theme.danger),
+        Priority::Medium => theme.fg(theme.warning),
+        Priority::Low => theme.fg(theme.success),
+        Priority::None => Style::default(),
+    }
+}
+
+// Merah kalau lewat jatuh tempo, kuning kalau jatuh tempo hari ini
+fn due_style(theme: &Theme, done: bool, due: NaiveDate, today: NaiveDate) -> Style {
+    if done {
+        Style::default()
+    } else if due < today {
+        theme.fg(theme.danger)
+    } else if due == today {
+        theme.fg(theme.warning)
+    } else {
+        theme.fg(theme.muted)
+    }
+}
+
+// Tabs di atas, daftar tugas di tengah, instruksi di bawah
+pub fn main_chunks(area: Rect) -> Vec<Rect> {
+    Layout::default()
+        .direction(Direction::Vertical)
+        .margin(1)
+        .constraints([
+            Constraint::Length(3),
+            Constraint::Min(1),
+            Constraint::Length(3),
+        ])
+        .split(area)
+        .to_vec()
+}
+
+// Popup tab terakhir dipakai (MRU), dibuka dengan `
+fn render_tab_switcher<B: Backend>(f: &mut Frame<B>, app: &App, theme: &Theme, cursor: usize) {
+    let items: Vec<ListItem> = app
+        .tabs_by_recency()
+        .into_iter()
+        .map(|index| {
+            let tab = &app.tabs[index];
+            ListItem::new(format!("{} ({})", tab.name, tab.list.items.len()))
+        })
+        .collect();
+    let height = (items.len() as u16 + 2).min(f.size().height);
+    let area = centered_rect(40, height, f.size());
+    let list = List::new(items)
+        .block(
+            theme
+                .block(Pane::Popup)
+                .title("Recent tabs (Enter to switch)"),
+        )
+        .highlight_style(theme.selection())
+        .highlight_symbol(">> ");
+    let mut state = ListState::default();
+    state.select(Some(cursor));
+    if render_fade(f, app, area) {
+        return;
+    }
+    f.render_widget(Clear, area);
+    f.render_stateful_widget(list, area, &mut state);
+}
+
+// Popup tab tujuan untuk m/c; angka di depan nama bisa ditekan langsung
+fn render_tab_picker<B: Backend>(
+    f: &mut Frame<B>,
+    app: &App,
+    theme: &Theme,
+    copy: bool,
+    cursor: usize,
+) {
+    let items: Vec<ListItem> = app
+        .tabs
+        .iter()
+        .enumerate()
+        .map(|(index, tab)| {
+            let number = if index < 9 {
+                format!("{} ", index + 1)
+            } else {
+                "  ".to_string()
+            };
+            let style = if index == app.active_tab && !copy {
+                theme.fg(theme.dim)
+            } else {
+                theme.fg(theme.text)
+            };
+            ListItem::new(Line::from(vec![
+                Span::styled(number, theme.fg(theme.badge)),
+                Span::raw(tab.name.clone()),
+            ]))
+            .style(style)
+        })
+        .collect();
+    let title = if copy { "Copy to tab" } else { "Move to tab" };
+    let height = (items.len() as u16 + 2).min(f.size().height);
+    let area = centered_rect(40, height, f.size());
+    let list = List::new(items)
+        .block(theme.block(Pane::Popup).title(title))
+        .highlight_style(theme.selection())
+        .highlight_symbol(">> ");
+    let mut state = ListState::default();
+    state.select(Some(cursor));
+    if render_fade(f, app, area) {
+        return;
+    }
+    f.render_widget(Clear, area);
+    f.render_stateful_widget(list, area, &mut state);
+}
+
+// Hasil pencarian global di atas daftar tugas, diawali nama tabnya
+fn render_search_results<B: Backend>(
+    f: &mut Frame<B>,
+    app: &App,
+    theme: &Theme,
+    cursor: usize,
+    area: Rect,
+) {
+    let matches = app.global_matches();
+    let items: Vec<ListItem> = matches
+        .iter()
+        .map(|&(tab, index)| {
+            let title = if app.privacy_mode {
+                REDACTED_TEXT
+            } else {
+                app.tabs[tab].list.items[index].title.as_str()
+            };
+            ListItem::new(Line::from(vec![
+                Span::styled(format!("{} › ", app.tabs[tab].name), theme.fg(theme.badge)),
+                Span::raw(title.to_string()),
+            ]))
+        })
+        .collect();
+    let list = List::new(items)
+        .block(
+            theme
+                .block(Pane::Tasks)
+                .title(format!("Results ({})", matches.len())),
+        )
+        .highlight_style(theme.selection())
+        .highlight_symbol(">> ");
+    let mut state = ListState::default();
+    state.select((!matches.is_empty()).then_some(cursor));
+    if render_fade(f, app, area) {
+        return;
+    }
+    f.render_widget(Clear, area);
+    f.render_stateful_widget(list, area, &mut state);
+}
+
+// Saran perintah di atas baris `:`; Tab memakai saran pertama
+fn render_command_suggestions<B: Backend>(f: &mut Frame<B>, app: &App, theme: &Theme, area: Rect) {
+    let suggestions = app.command_completions();
+    if suggestions.is_empty() {
+        return;
+    }
+    let items: Vec<ListItem> = suggestions
+        .iter()
+        .map(|line| {
+            // Nama perintah saja: tampilkan cara pakainya sekalian
+            let text = command::usage_for(line)
+                .filter(|_| !line.contains(' '))
+                .unwrap_or(line);
+            ListItem::new(text.to_string())
+        })
+        .collect();
+    let height = (items.len() as u16 + 2).min(area.height);
+    let area = Rect::new(area.x, area.bottom() - height, area.width, height);
+    let list = List::new(items)
+        .block(theme.block(Pane::Popup).title("Commands"))
+        .style(theme.fg(theme.accent));
+    f.render_widget(Clear, area);
+    f.render_widget(list, area);
+}
+
+// Popup `?`: semua tombol dari keymap yang aktif, per kategori
+fn render_help_popup<B: Backend>(f: &mut Frame<B>, app: &mut App, theme: &Theme, offset: u16) {
+    let mut lines = Vec::new();
+    for (category, rows) in app.keymap.help() {
+        if rows.is_empty() {
+            continue;
+        }
+        if !lines.is_empty() {
+            lines.push(Line::from(""));
+        }
+        lines.push(Line::from(Span::styled(
+            category.label(),
+            theme.fg(theme.accent).add_modifier(Modifier::BOLD),
+        )));
+        for (keys, description) in rows {
+            lines.push(Line::from(vec![
+                Span::styled(format!("  {:<18} ", keys), theme.fg(theme.badge)),
+                Span::styled(description, theme.fg(theme.text)),
+            ]));
+        }
+    }
+    let size = f.size();
+    let height = (lines.len() as u16 + 2).min(size.height);
+    let area = centered_rect(60, height, size);
+    // Jangan scroll melewati baris terakhir
+    let max_offset = (lines.len() as u16).saturating_sub(area.height.saturating_sub(2));
+    let offset = offset.min(max_offset);
+    app.input_mode = InputMode::ShowingHelp(offset);
+    let help = Paragraph::new(lines)
+        .block(
+            theme
+                .block(Pane::Popup)
+                .title("Keys (↑/↓ scroll, Esc to close)"),
+        )
+        .scroll((offset, 0));
+    if render_fade(f, app, area) {
+        return;
+    }
+    f.render_widget(Clear, area);
+    f.render_widget(help, area);
+}
+
+// QR dari link tugas terpilih supaya bisa dibuka dari HP
+fn render_qr_popup<B: Backend>(f: &mut Frame<B>, app: &App) {
+    let link = app.selected_link().filter(|_| !app.privacy_mode);
+    let code = link
+        .as_ref()
+        .and_then(|link| QrCode::new(link.as_bytes()).ok());
+    let size = f.size();
+    let (text, width, height) = match code {
+        Some(code) => {
+            // Modul gelap jadi spasi karena latar popup hitam
+            let qr = code
+                .render::<Dense1x2>()
+                .dark_color(Dense1x2::Light)
+                .light_color(Dense1x2::Dark)
+                .build();
+            let width = qr
+                .lines()
+                .map(|line| line.chars().count())
+                .max()
+                .unwrap_or(0) as u16;
+            let height = qr.lines().count() as u16;
+            (qr, width + 2, height + 2)
+        }
+        None => {
+            let message = if app.privacy_mode {
+                "Link hidden in privacy mode."
+            } else {
+                "No http(s) link in this task."
+            };
+            (message.to_string(), message.len() as u16 + 2, 3)
+        }
+    };
+    let (text, width, height) = if width > size.width || height > size.height {
+        let message = "Terminal too small for the QR code.";
+        (message.to_string(), message.len() as u16 + 2, 3)
+    } else {
+        (text, width, height)
+    };
+    let area = Rect::new(
+        size.x + size.width.saturating_sub(width) / 2,
+        size.y + size.height.saturating_sub(height) / 2,
+        width.min(size.width),
+        height.min(size.height),
+    );
+    // Border polos tetap, ukuran popup dihitung dari QR-nya
+    let popup = Paragraph::new(text)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(link.as_deref().unwrap_or("Link")),
+        )
+        // Selalu putih di atas hitam, apa pun temanya, supaya mudah dipindai
+        .style(Style::default().fg(Color::White).bg(Color::Black));
+    if render_fade(f, app, area) {
+        return;
+    }
+    f.render_widget(Clear, area);
+    f.render_widget(popup, area);
+}
+
+// Selama animasi fade, isi area popup dengan ░ ▒ ▓ dan lewati isinya
+fn render_fade<B: Backend>(f: &mut Frame<B>, app: &App, area: Rect) -> bool {
+    let Some((Animation::PopupFade, progress)) = app.animation_progress() else {
+        return false;
+    };
+    let shade = match progress {
+        p if p < 0.33 => "░",
+        p if p < 0.66 => "▒",
+        _ => "▓",
+    };
+    let row = shade.repeat(area.width as usize);
+    let text = vec![row; area.height as usize].join("\n");
+    f.render_widget(Clear, area);
+    f.render_widget(
+        Paragraph::new(text).style(app.theme().fg(app.theme().dim)),
+        area,
+    );
+    true
+}
+
+// Baris pertama yang terlihat, dihitung seperti List milik ratatui untuk baris
+// setinggi satu: seleksi (atau baris pertama kalau tidak ada) selalu terlihat
+fn list_offset(offset: usize, selected: Option<usize>, len: usize, height: usize) -> usize {
+    if len == 0 {
+        return 0;
+    }
+    let offset = offset.min(len - 1);
+    let selected = selected.unwrap_or(0).min(len - 1);
+    if selected >= offset + height {
+        selected + 1 - height
+    } else {
+        offset.min(selected)
+    }
+}
+
+// Kotak di tengah `area`: lebar dalam persen, tinggi dalam baris
+fn centered_rect(percent_x: u16, height: u16, area: Rect) -> Rect {
+    let width = area.width * percent_x / 100;
+    Rect::new(
+        area.x + (area.width - width) / 2,
+        area.y + area.height.saturating_sub(height) / 2,
+        width,
+        height.min(area.height),
+    )
+}
+
+// Satu frame spinner per SPINNER_FRAME sejak loading mulai
+const SPINNER: [char; 10] = ['⠋', '⠙', '⠹', '⠸', '⠼', '⠴', '⠦', '⠧', '⠇', '⠏'];
+const SPINNER_FRAME: Duration = Duration::from_millis(80);
+
+// Layar loading saat membaca file data yang besar; `ratio` 0.0 - 1.0. `elapsed`
+// (waktu sejak loading mulai) hanya diisi kalau animasi aktif, untuk spinner.
+pub fn draw_loading<B: Backend>(
+    f: &mut Frame<B>,
+    theme: &Theme,
+    name: &str,
+    ratio: f64,
+    elapsed: Option<Duration>,
+) {
+    let area = centered_rect(60, 3, f.size());
+    let title = match elapsed {
+        Some(elapsed) => {
+            let frame = (elapsed.as_millis() / SPINNER_FRAME.as_millis()) as usize;
+            format!("{} Loading {}", SPINNER[frame % SPINNER.len()], name)
+        }
+        None => format!("Loading {}", name),
+    };
+    let gauge = Gauge::default()
+        .block(theme.block(Pane::Popup).title(title))
+        .gauge_style(Style::default().fg(theme.accent).bg(theme.dim))
+        .ratio(ratio.clamp(0.0, 1.0))
+        .label(format!("{:.0}%", ratio * 100.0));
+    f.render_widget(gauge, area);
+}
+
+fn render_lock_screen<B: Backend>(f: &mut Frame<B>, theme: &Theme) {
+    let size = f.size();
+    let height = 3.min(size.height);
+    let area = Rect::new(
+        0,
+        size.height.saturating_sub(height) / 2,
+        size.width,
+        height,
+    );
+    let message = Paragraph::new("Locked after inactivity. Press any key to resume.")
+        .alignment(Alignment::Center)
+        .block(theme.block(Pane::Popup).title("Locked"))
+        .style(theme.fg(theme.muted));
+    f.render_widget(Clear, size);
+    f.render_widget(message, area);
+}
+
+fn render_debug_overlay<B: Backend>(f: &mut Frame<B>, app: &App, theme: &Theme) {
+    let size = f.size();
+    let width = 34.min(size.width);
+    let height = 9.min(size.height);
+    let area = Rect::new(size.width - width, 0, width, height);
+
+    let memory = app.memory_report();
+    let text = format!(
+        "frame: {:.2} ms\nframes: {}\nevents/s: {}\ntasks: {} in {} tabs\n\
+         memory: ~{} KiB\n  tasks/text: {}/{} KiB\n  undo {} KiB",
+        app.debug.last_frame.as_secs_f64() * 1000.0,
+        app.debug.frames,
+        app.debug.events_per_sec(),
+        app.task_count(),
+        app.tabs.len(),
+        memory.total() / 1024,
+        memory.structs / 1024,
+        memory.text / 1024,
+        memory.history / 1024,
+    );
+    let overlay = Paragraph::new(text)
+        .block(theme.block(Pane::Popup).title("Debug"))
+        .style(theme.fg(theme.success));
+    f.render_widget(Clear, area);
+    f.render_widget(overlay, area);
+}
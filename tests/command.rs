@@ -0,0 +1,101 @@
+use fauzyrustproject::{
+    command::{self, Command, PriorityChange},
+    Priority,
+};
+
+fn parse(input: &str) -> String {
+    match command::parse(input) {
+        Ok(command) => format!("{:?}", command),
+        Err(err) => format!("error: {}", err),
+    }
+}
+
+#[test]
+fn parse_commands_and_arguments() {
+    assert_eq!(parse("add Buy milk tomorrow"), "Add(\"Buy milk tomorrow\")");
+    assert_eq!(parse("  edit   Pay rent  "), "Edit(\"Pay rent\")");
+    assert_eq!(parse("notes"), "Notes(\"\")");
+    assert_eq!(parse("status prev"), "Status(false)");
+    assert_eq!(parse("status"), "Status(true)");
+    assert!(matches!(
+        command::parse("priority low"),
+        Ok(Command::Priority(PriorityChange::Set(Priority::Low)))
+    ));
+    assert_eq!(parse("move down"), "Move(true)");
+    assert_eq!(
+        parse("statuses Backlog, In progress ,,Done"),
+        "Statuses([\"Backlog\", \"In progress\", \"Done\"])"
+    );
+    assert_eq!(parse("statuses"), "Statuses([])");
+    assert_eq!(parse("tags #Home"), "Tags(Some(\"home\"))");
+    assert_eq!(parse("tags"), "Tags(None)");
+    assert_eq!(parse("theme"), "Theme(None)");
+    assert!(matches!(
+        command::parse("export tab out/today.md"),
+        Ok(Command::Export { path, all: false }) if path.as_os_str() == "out/today.md"
+    ));
+    assert!(matches!(
+        command::parse("export all.json"),
+        Ok(Command::Export { path, all: true }) if path.as_os_str() == "all.json"
+    ));
+}
+
+#[test]
+fn parse_errors_show_usage() {
+    assert_eq!(
+        parse("add"),
+        "error: usage: add <title> [today|tomorrow|mon..sun|YYYY-MM-DD]"
+    );
+    assert_eq!(
+        parse("priority urgent"),
+        "error: usage: priority up|down|high|medium|low|none"
+    );
+    assert_eq!(parse("move"), "error: usage: move up|down");
+    for input in ["statuses Done", "statuses Todo,Done,Todo"] {
+        assert_eq!(
+            parse(input),
+            "error: statuses: need at least two different names"
+        );
+    }
+}
+
+// Nama lengkap, alias q, awalan unik; awalan yang cocok dengan beberapa perintah ditolak
+#[test]
+fn command_names_resolve_by_prefix() {
+    assert_eq!(parse("q"), "Quit");
+    assert_eq!(parse("quit"), "Quit");
+    assert_eq!(parse("und"), "Undo");
+    assert_eq!(parse("tabc"), "CloseTab");
+    // "tag" cocok persis walau "tags" juga berawalan "tag"
+    assert_eq!(parse("tag work"), "Tag(\"work\")");
+    assert_eq!(parse("del"), "Delete");
+    assert_eq!(
+        parse("de"),
+        "error: ambiguous command: de (delete, details, debug)"
+    );
+    assert_eq!(parse("frobnicate"), "error: unknown command: frobnicate");
+}
+
+#[test]
+fn completions_for_names_and_arguments() {
+    let tabs = vec!["Inbox".to_string(), "Later".to_string()];
+    let tags = vec!["home".to_string(), "work".to_string()];
+    let themes = ["dark", "light"];
+    let complete = |input: &str| command::completions(input, &tabs, &tags, &themes);
+
+    assert_eq!(complete("tab"), ["tab", "tabnew", "tabrename", "tabclose"]);
+    assert_eq!(complete("x"), Vec::<String>::new());
+    assert_eq!(complete("tab "), ["tab Inbox", "tab Later"]);
+    // Argumen dicocokkan tanpa peduli huruf besar; awalan nama perintah ikut diperluas
+    assert_eq!(complete("movet l"), ["moveto Later"]);
+    assert_eq!(complete("tags W"), ["tags work"]);
+    assert_eq!(complete("theme "), ["theme dark", "theme light"]);
+    assert_eq!(complete("priority h"), ["priority high"]);
+    // Perintah tanpa pilihan argumen, atau nama yang ambigu
+    assert_eq!(complete("add "), Vec::<String>::new());
+    assert_eq!(complete("de x"), Vec::<String>::new());
+    assert_eq!(
+        command::usage_for("moveto La"),
+        Some("moveto <tab name|number>")
+    );
+}
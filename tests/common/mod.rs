@@ -0,0 +1,234 @@
+// Harness untuk test end-to-end: tombol dari skrip dijalankan seperti di loop utama
+// (gambar dulu, lalu proses event) terhadap TestBackend, lalu state aplikasi dan
+// isi layar bisa dicek.
+#![allow(dead_code)]
+
+use crossterm::event::{KeyEvent, MouseButton, MouseEvent, MouseEventKind};
+use fauzyrustproject::{
+    event::{handle_key, handle_mouse},
+    keymap::KeyChord,
+    notes::NoteFile,
+    storage, ui, App,
+};
+use ratatui::{backend::TestBackend, Terminal};
+use std::{
+    fs,
+    path::PathBuf,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+pub const WIDTH: u16 = 100;
+pub const HEIGHT: u16 = 30;
+
+pub struct Harness {
+    pub app: App,
+    pub terminal: Terminal<TestBackend>,
+    data: DataDir,
+}
+
+impl Harness {
+    pub fn new(app: App) -> Self {
+        Self::with_size(app, WIDTH, HEIGHT)
+    }
+
+    pub fn with_size(mut app: App, width: u16, height: u16) -> Self {
+        let terminal = Terminal::new(TestBackend::new(width, height)).expect("test terminal");
+        let data = DataDir::new();
+        app.note_file = Some(NoteFile::for_data(&data.file, None));
+        let mut harness = Self {
+            app,
+            terminal,
+            data,
+        };
+        harness.draw();
+        harness
+    }
+
+    pub fn draw(&mut self) {
+        let app = &mut self.app;
+        self.terminal.draw(|f| ui::draw(f, app)).expect("draw");
+    }
+
+    // Satu karakter = satu tombol; tombol bernama ditulis dalam kurung sudut,
+    // memakai format config: "aBeli susu<enter><space>u<ctrl+r>q". `<lt>` untuk "<".
+    // Seperti loop utama, tombol setelah aplikasi keluar tidak diproses.
+    pub fn keys(&mut self, script: &str) {
+        for key in parse_script(script) {
+            if self.app.should_quit {
+                break;
+            }
+            handle_key(&mut self.app, key);
+            self.draw();
+        }
+    }
+
+    // Teks apa adanya, termasuk "<"
+    pub fn type_text(&mut self, text: &str) {
+        for c in text.chars() {
+            let chord = KeyChord::parse(&c.to_string()).expect("printable key");
+            handle_key(&mut self.app, chord.into());
+            self.draw();
+        }
+    }
+
+    pub fn click(&mut self, column: u16, row: u16) {
+        self.mouse(MouseEventKind::Down(MouseButton::Left), column, row);
+    }
+
+    pub fn scroll(&mut self, down: bool, column: u16, row: u16) {
+        let kind = if down {
+            MouseEventKind::ScrollDown
+        } else {
+            MouseEventKind::ScrollUp
+        };
+        self.mouse(kind, column, row);
+    }
+
+    fn mouse(&mut self, kind: MouseEventKind, column: u16, row: u16) {
+        handle_mouse(
+            &mut self.app,
+            MouseEvent {
+                kind,
+                column,
+                row,
+                modifiers: crossterm::event::KeyModifiers::NONE,
+            },
+        );
+        self.draw();
+    }
+
+    // Isi layar terakhir, satu baris per baris terminal
+    pub fn screen(&self) -> String {
+        let buffer = self.terminal.backend().buffer();
+        let width = buffer.area.width as usize;
+        buffer
+            .content
+            .chunks(width)
+            .map(|row| {
+                row.iter()
+                    .map(|cell| cell.symbol.as_str())
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    pub fn row(&self, y: u16) -> String {
+        self.screen()
+            .lines()
+            .nth(y as usize)
+            .unwrap_or_default()
+            .to_string()
+    }
+
+    // Posisi (kolom, baris) kemunculan pertama teks di layar, untuk klik
+    pub fn find(&self, text: &str) -> Option<(u16, u16)> {
+        self.screen().lines().enumerate().find_map(|(y, line)| {
+            let byte = line.find(text)?;
+            Some((line[..byte].chars().count() as u16, y as u16))
+        })
+    }
+
+    #[track_caller]
+    pub fn assert_screen_contains(&self, text: &str) {
+        let screen = self.screen();
+        assert!(
+            screen.contains(text),
+            "{:?} not on screen:\n{}",
+            text,
+            screen
+        );
+    }
+
+    #[track_caller]
+    pub fn assert_screen_lacks(&self, text: &str) {
+        let screen = self.screen();
+        assert!(
+            !screen.contains(text),
+            "{:?} still on screen:\n{}",
+            text,
+            screen
+        );
+    }
+
+    pub fn titles(&self) -> Vec<String> {
+        self.app
+            .current_tab()
+            .list
+            .items
+            .iter()
+            .map(|task| task.title.clone())
+            .collect()
+    }
+
+    // File di folder data sementara harness, misalnya untuk :export
+    pub fn temp_path(&self, name: &str) -> PathBuf {
+        self.data.dir.join(name)
+    }
+
+    // Simpan seperti saat keluar, lalu jalankan ulang dari file yang sama
+    pub fn reload(mut self) -> Self {
+        self.app.store_notes().expect("store notes");
+        storage::save(&self.data.file, &self.app.to_database()).expect("save");
+        let database = storage::load(&self.data.file)
+            .expect("load")
+            .expect("data file exists");
+        let note_file = NoteFile::for_data(&self.data.file, database.notes_file.as_deref());
+        let mut app = App::from_database(database);
+        app.note_file = Some(note_file);
+        let size = self.terminal.size().expect("size");
+        let terminal = Terminal::new(TestBackend::new(size.width, size.height)).expect("terminal");
+        let mut harness = Self {
+            app,
+            terminal,
+            data: self.data,
+        };
+        harness.draw();
+        harness
+    }
+}
+
+fn parse_script(script: &str) -> Vec<KeyEvent> {
+    let mut keys = Vec::new();
+    let mut chars = script.chars();
+    while let Some(c) = chars.next() {
+        let name = if c == '<' {
+            let name: String = chars.by_ref().take_while(|&c| c != '>').collect();
+            if name == "lt" {
+                "<".to_string()
+            } else {
+                name
+            }
+        } else {
+            c.to_string()
+        };
+        let chord = KeyChord::parse(&name).unwrap_or_else(|err| panic!("{}", err));
+        keys.push(chord.into());
+    }
+    keys
+}
+
+// Folder data sementara per harness, dihapus saat selesai
+struct DataDir {
+    dir: PathBuf,
+    file: PathBuf,
+}
+
+impl DataDir {
+    fn new() -> Self {
+        static NEXT: AtomicUsize = AtomicUsize::new(0);
+        let dir = std::env::temp_dir().join(format!(
+            "firsttui-test-{}-{}",
+            std::process::id(),
+            NEXT.fetch_add(1, Ordering::Relaxed)
+        ));
+        let file = dir.join("tasks.json");
+        Self { dir, file }
+    }
+}
+
+impl Drop for DataDir {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.dir);
+    }
+}
@@ -0,0 +1,31 @@
+use fauzyrustproject::config;
+use std::time::Duration;
+
+#[test]
+fn lock_after_is_read_in_seconds() {
+    let config = config::parse("lock_after = 300\n").unwrap();
+    assert_eq!(config.lock_after(), Some(Duration::from_secs(300)));
+    // 0 dan tidak ditulis sama-sama berarti tidak pernah terkunci
+    assert_eq!(
+        config::parse("lock_after = 0\n").unwrap().lock_after(),
+        None
+    );
+    assert_eq!(config::parse("").unwrap().lock_after(), None);
+    assert!(config::parse("lock_after = -5\n").is_err());
+    // --lock-after memakai aturan yang sama
+    assert_eq!(config::lock_timeout(Some(0)), None);
+    assert_eq!(
+        config::lock_timeout(Some(60)),
+        Some(Duration::from_secs(60))
+    );
+}
+
+#[test]
+fn edit_in_place_can_be_set_in_config() {
+    assert!(
+        config::parse("edit_in_place = true\n")
+            .unwrap()
+            .edit_in_place
+    );
+    assert!(!config::parse("").unwrap().edit_in_place);
+}
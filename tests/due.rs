@@ -0,0 +1,84 @@
+use chrono::NaiveDate;
+use fauzyrustproject::due::parse_quick_entry;
+
+fn date(text: &str) -> NaiveDate {
+    NaiveDate::parse_from_str(text, "%Y-%m-%d").unwrap()
+}
+
+// Rabu
+fn today() -> NaiveDate {
+    date("2024-05-01")
+}
+
+fn parsed(input: &str) -> (String, Option<NaiveDate>) {
+    parse_quick_entry(input, today())
+}
+
+#[test]
+fn today_and_tomorrow() {
+    assert_eq!(parsed("Buy milk today"), ("Buy milk".into(), Some(today())));
+    assert_eq!(
+        parsed("Buy milk tomorrow"),
+        ("Buy milk".into(), Some(date("2024-05-02")))
+    );
+    assert_eq!(
+        parsed("Buy milk TMR"),
+        ("Buy milk".into(), Some(date("2024-05-02")))
+    );
+    // Akhir bulan dan tahun
+    assert_eq!(
+        parse_quick_entry("Party tomorrow", date("2024-12-31")).1,
+        Some(date("2025-01-01"))
+    );
+}
+
+#[test]
+fn weekday_names_mean_the_next_one() {
+    for (word, expected) in [
+        ("thu", "2024-05-02"),
+        ("Friday", "2024-05-03"),
+        ("sun", "2024-05-05"),
+        ("mon", "2024-05-06"),
+        ("tues", "2024-05-07"),
+        // Hari yang sama berarti hari ini
+        ("wed", "2024-05-01"),
+        ("wednesday", "2024-05-01"),
+    ] {
+        assert_eq!(
+            parsed(&format!("Report {}", word)),
+            ("Report".into(), Some(date(expected))),
+            "{}",
+            word
+        );
+    }
+}
+
+#[test]
+fn iso_dates() {
+    assert_eq!(
+        parsed("Renew passport 2025-02-28"),
+        ("Renew passport".into(), Some(date("2025-02-28")))
+    );
+    // Spasi berlebih dibuang
+    assert_eq!(
+        parsed("  Renew   passport   2025-02-28  "),
+        ("Renew   passport".into(), Some(date("2025-02-28")))
+    );
+    // Tanggal yang tidak ada bukan jatuh tempo
+    assert_eq!(parsed("Leap 2023-02-29"), ("Leap 2023-02-29".into(), None));
+}
+
+#[test]
+fn input_without_a_date_word_is_kept() {
+    for input in [
+        "Buy milk",
+        "Call about tomorrow's meeting",
+        "Friday plans",
+        "Due 05/03/2024",
+        // Kata tanggal saja tidak menjadi judul kosong
+        "tomorrow",
+        "",
+    ] {
+        assert_eq!(parsed(input), (input.to_string(), None), "{:?}", input);
+    }
+}
@@ -0,0 +1,388 @@
+use chrono::{Local, NaiveDate, TimeZone};
+use fauzyrustproject::{
+    export::{ical, markdown, todotxt, Format},
+    notes::Notes,
+    storage::TabRecord,
+    Priority, Task,
+};
+use std::path::Path;
+
+fn date(text: &str) -> NaiveDate {
+    NaiveDate::parse_from_str(text, "%Y-%m-%d").unwrap()
+}
+
+fn tab(name: &str, tasks: Vec<Task>) -> TabRecord {
+    TabRecord {
+        name: name.to_string(),
+        statuses: Vec::new(),
+        tasks,
+    }
+}
+
+fn sample() -> Vec<TabRecord> {
+    let report = Task {
+        priority: Priority::High,
+        due: Some(date("2024-05-03")),
+        created: Some(Local.with_ymd_and_hms(2024, 5, 1, 9, 30, 0).unwrap()),
+        notes: Notes::text("first line\n\nthird line"),
+        ..Task::new("Finish report #work")
+    };
+    let email = Task {
+        done: true,
+        priority: Priority::Medium,
+        created: None,
+        ..Task::new("Email manager")
+    };
+    let milk = Task {
+        created: None,
+        ..Task::new("Buy milk #home")
+    };
+    vec![
+        tab("Work", vec![report, email]),
+        tab("Side projects", vec![milk]),
+    ]
+}
+
+#[test]
+fn format_follows_extension() {
+    assert_eq!(Format::from_path(Path::new("a/tasks.md")), Format::Markdown);
+    assert_eq!(Format::from_path(Path::new("todo.TXT")), Format::TodoTxt);
+    assert_eq!(Format::from_path(Path::new("backup.json")), Format::Json);
+    assert_eq!(Format::from_path(Path::new("backup")), Format::Json);
+    assert_eq!(Format::from_path(Path::new("due.ics")), Format::Ical);
+}
+
+#[test]
+fn markdown_checklist() {
+    assert_eq!(
+        markdown::render(&sample()),
+        "## Work\n\
+         \n\
+         - [ ] Finish report #work (priority: high, due: 2024-05-03)\n  \
+         > first line\n  \
+         >\n  \
+         > third line\n\
+         - [x] Email manager (priority: medium)\n\
+         \n\
+         ## Side projects\n\
+         \n\
+         - [ ] Buy milk #home\n"
+    );
+}
+
+#[test]
+fn icalendar_todos() {
+    let mut tabs = sample();
+    tabs[0].tasks[1].due = Some(date("2024-05-10"));
+    let now = Local.with_ymd_and_hms(2024, 5, 2, 8, 0, 0).unwrap();
+    let utc = |time: chrono::DateTime<Local>| {
+        time.with_timezone(&chrono::Utc)
+            .format("%Y%m%dT%H%M%SZ")
+            .to_string()
+    };
+    let calendar = ical::render(&tabs, now);
+    let lines: Vec<&str> = calendar.split("\r\n").collect();
+    // Buy milk tidak punya jatuh tempo, jadi tidak ikut
+    assert_eq!(ical::count(&tabs), 2);
+    assert_eq!(
+        lines.iter().filter(|line| **line == "BEGIN:VTODO").count(),
+        2
+    );
+    assert_eq!(
+        lines[..3],
+        [
+            "BEGIN:VCALENDAR",
+            "VERSION:2.0",
+            "PRODID:-//firsttui//tasks//EN"
+        ]
+    );
+    assert_eq!(lines[lines.len() - 2..], ["END:VCALENDAR", ""]);
+
+    let report = &lines[3..lines.iter().position(|line| *line == "END:VTODO").unwrap()];
+    assert!(report[1].starts_with("UID:") && report[1].ends_with("@firsttui"));
+    assert_eq!(
+        report[2..],
+        [
+            format!("DTSTAMP:{}", utc(now)).as_str(),
+            &format!(
+                "CREATED:{}",
+                utc(Local.with_ymd_and_hms(2024, 5, 1, 9, 30, 0).unwrap())
+            ),
+            "SUMMARY:Finish report #work",
+            "DESCRIPTION:first line\\n\\nthird line",
+            "DUE;VALUE=DATE:20240503",
+            "PRIORITY:1",
+            "STATUS:NEEDS-ACTION",
+            "CATEGORIES:Work,work",
+        ]
+    );
+    assert!(lines.contains(&"SUMMARY:Email manager"));
+    assert!(lines.contains(&"PRIORITY:5"));
+    assert!(lines.contains(&"STATUS:COMPLETED"));
+    // Ekspor ulang menghasilkan UID yang sama
+    assert_eq!(ical::render(&tabs, now), calendar);
+}
+
+#[test]
+fn icalendar_escaping_status_and_folding() {
+    let long = Task {
+        due: Some(date("2024-06-01")),
+        status: Some("Review".into()),
+        notes: Notes::text("a;b,c\\d"),
+        ..Task::new(format!("Plan, then {}", "é".repeat(60)))
+    };
+    let mut board = tab("Board", vec![long]);
+    board.statuses = vec!["Todo".to_string(), "Review".to_string(), "Done".to_string()];
+    let calendar = ical::render(&[board], Local::now());
+    assert!(calendar.contains("DESCRIPTION:a\\;b\\,c\\\\d\r\n"));
+    assert!(calendar.contains("STATUS:IN-PROCESS\r\n"));
+    for line in calendar.split("\r\n") {
+        assert!(line.len() <= 75, "{:?}", line);
+    }
+    // Baris lanjutan diawali spasi; setelah disambung isinya utuh
+    let unfolded = calendar.replace("\r\n ", "");
+    assert!(unfolded.contains(&format!("SUMMARY:Plan\\, then {}\r\n", "é".repeat(60))));
+}
+
+#[test]
+fn icalendar_uids_are_unique_and_stable() {
+    let uids = |tabs: &[TabRecord]| -> Vec<String> {
+        ical::render(tabs, Local::now())
+            .split("\r\n")
+            .filter_map(|line| line.strip_prefix("UID:"))
+            .map(str::to_string)
+            .collect()
+    };
+    let due = |task: Task| Task {
+        due: Some(date("2024-06-01")),
+        ..task
+    };
+    let report = sample()[0].tasks[0].clone();
+    let undated = |title: &str| Task {
+        created: None,
+        ..due(Task::new(title))
+    };
+    // Salinan di tab yang sama (waktu dibuat sama) dan tugas tanpa tanggal dibuat
+    // dengan judul yang sama
+    let mut tabs = vec![tab(
+        "Work",
+        vec![
+            report.clone(),
+            undated("Call bank"),
+            report.clone(),
+            undated("Call bank"),
+        ],
+    )];
+    let first = uids(&tabs);
+    let mut unique = first.clone();
+    unique.sort();
+    unique.dedup();
+    assert_eq!(unique.len(), 4, "{:?}", first);
+
+    // Tugas lain yang ditambah atau dipindah tidak mengubah UID yang sudah ada
+    tabs[0].tasks.insert(1, due(Task::new("Something else")));
+    let again = uids(&tabs);
+    assert_eq!(
+        [again[0].as_str(), again[2].as_str()],
+        [first[0].as_str(), first[1].as_str()]
+    );
+    assert_eq!(again[3..], first[2..]);
+}
+
+#[test]
+fn todotxt_lines() {
+    assert_eq!(
+        todotxt::render(&sample()),
+        "(A) 2024-05-01 Finish report +work due:2024-05-03 tab:Work\n\
+         x Email manager pri:B tab:Work\n\
+         Buy milk +home tab:Side%20projects\n"
+    );
+    // Satu tab saja: tanpa tab:Nama
+    assert_eq!(todotxt::render(&sample()[1..]), "Buy milk +home\n");
+    // Tugas selesai tidak membawa tanggal, walau punya tanggal dibuat
+    let mut report = sample().swap_remove(0);
+    report.tasks[0].done = true;
+    assert_eq!(
+        todotxt::render(&[report]).lines().next(),
+        Some("x Finish report +work due:2024-05-03 pri:A")
+    );
+}
+
+#[test]
+fn todotxt_protects_titles_that_look_like_markers() {
+    let tasks = vec![
+        Task {
+            created: None,
+            priority: Priority::High,
+            ..Task::new("x marks the spot")
+        },
+        Task {
+            done: true,
+            ..Task::new("2024-01-09 Pay rent")
+        },
+    ];
+    assert_eq!(
+        todotxt::render(&[tab("Inbox", tasks)]),
+        "pri:A tab:Inbox x marks the spot\n\
+         x tab:Inbox 2024-01-09 Pay rent\n"
+    );
+}
+
+#[test]
+fn todotxt_round_trip() {
+    let tabs = todotxt::parse(&todotxt::render(&sample()), "todo");
+    let names: Vec<&str> = tabs.iter().map(|tab| tab.name.as_str()).collect();
+    assert_eq!(names, ["Work", "Side projects"]);
+
+    let report = &tabs[0].tasks[0];
+    assert_eq!(report.title, "Finish report #work");
+    assert_eq!(report.priority, Priority::High);
+    assert_eq!(report.due, Some(date("2024-05-03")));
+    assert_eq!(
+        report.created.map(|created| created.date_naive()),
+        Some(date("2024-05-01"))
+    );
+    assert!(!report.done);
+
+    let email = &tabs[0].tasks[1];
+    assert!(email.done);
+    assert_eq!(email.priority, Priority::Medium);
+    assert_eq!(tabs[1].tasks[0].title, "Buy milk #home");
+}
+
+#[test]
+fn todotxt_import_from_other_apps() {
+    let text = "\
+(B) 2024-01-02 Call bank @phone +finance
+x 2024-01-05 2024-01-01 Pay rent due:2024-01-03
+
+(Z) Water plants
+(a) lowercase is not a priority
+x
+Plain task due:someday
+";
+    let tabs = todotxt::parse(text, "todo");
+    assert_eq!(tabs.len(), 1);
+    assert_eq!(tabs[0].name, "todo");
+    let tasks = &tabs[0].tasks;
+    let titles: Vec<&str> = tasks.iter().map(|task| task.title.as_str()).collect();
+    assert_eq!(
+        titles,
+        [
+            "Call bank #phone #finance",
+            "Pay rent",
+            "Water plants",
+            "(a) lowercase is not a priority",
+            "x",
+            "Plain task due:someday",
+        ]
+    );
+    assert_eq!(tasks[0].priority, Priority::Medium);
+    assert_eq!(
+        tasks[0].created.map(|created| created.date_naive()),
+        Some(date("2024-01-02"))
+    );
+    // Tanggal pertama setelah "x" adalah tanggal selesai, yang kedua tanggal dibuat
+    assert!(tasks[1].done);
+    assert_eq!(
+        tasks[1].created.map(|created| created.date_naive()),
+        Some(date("2024-01-01"))
+    );
+    assert_eq!(tasks[1].due, Some(date("2024-01-03")));
+    assert_eq!(tasks[2].priority, Priority::Low);
+    assert_eq!(tasks[3].priority, Priority::None);
+    // Baris yang hanya berisi penanda tidak dibuang
+    assert!(!tasks[4].done);
+    assert_eq!(tasks[5].due, None);
+}
+
+// Kasus yang dulu berubah atau hilang setelah ekspor lalu impor ulang (ditemukan fuzzing)
+#[test]
+fn todotxt_round_trip_regressions() {
+    for text in [
+        "(A) due:2024-02-03",
+        "x x",
+        "x 2024-01-05 2024-01-01 2024-01-09 Pay rent",
+        "due:2024-01-01 x marks the spot",
+        "2024-01-01 pri:B",
+        "tab:a_b (Q) pri:",
+        "Release +2024-01-01",
+        "(A) 2024-01-01",
+    ] {
+        let imported = todotxt::parse(text, "todo");
+        let rendered = todotxt::render(&imported);
+        let again = todotxt::parse(&rendered, "todo");
+        assert_eq!(
+            fields(&imported),
+            fields(&again),
+            "{:?} -> {:?}",
+            text,
+            rendered
+        );
+    }
+}
+
+#[test]
+fn todotxt_tab_names_round_trip() {
+    let names = [
+        "work_items",
+        "Side projects",
+        "100% done",
+        "a%20b",
+        "tab\there",
+    ];
+    let tabs: Vec<TabRecord> = names
+        .iter()
+        .map(|name| tab(name, vec![Task::new("Task")]))
+        .collect();
+    let rendered = todotxt::render(&tabs);
+    assert!(rendered.contains(" tab:work_items\n"), "{}", rendered);
+    assert!(rendered.contains(" tab:100%25%20done\n"), "{}", rendered);
+    let imported: Vec<String> = todotxt::parse(&rendered, "todo")
+        .into_iter()
+        .map(|tab| tab.name)
+        .collect();
+    assert_eq!(imported, names);
+    // "%" tanpa dua digit hex dibaca apa adanya
+    assert_eq!(
+        todotxt::parse("a tab:50%+1\nb tab:x%2", "todo")[1].name,
+        "x%2"
+    );
+    assert_eq!(todotxt::parse("a tab:50%+1", "todo")[0].name, "50%+1");
+}
+
+#[test]
+fn todotxt_dates_must_be_exact() {
+    let tabs = todotxt::parse("2024-1-5 +2024-01-01 due:2024-1-5", "todo");
+    let task = &tabs[0].tasks[0];
+    assert_eq!(task.title, "2024-1-5 #2024-01-01 due:2024-1-5");
+    assert_eq!(task.created, None);
+    assert_eq!(task.due, None);
+}
+
+// Yang dibawa todo.txt. Nama tab hanya ditulis kalau ada lebih dari satu tab, dan
+// tugas selesai tidak membawa tanggal dibuat.
+fn fields(tabs: &[TabRecord]) -> Vec<String> {
+    tabs.iter()
+        .flat_map(|tab| {
+            let name = if tabs.len() > 1 {
+                tab.name.as_str()
+            } else {
+                ""
+            };
+            tab.tasks.iter().map(move |task| {
+                format!(
+                    "{} {:?} {} {:?} {:?} {:?}",
+                    name,
+                    task.title,
+                    task.done,
+                    task.priority,
+                    task.due,
+                    task.created
+                        .filter(|_| !task.done)
+                        .map(|created| created.date_naive())
+                )
+            })
+        })
+        .collect()
+}
@@ -0,0 +1,438 @@
+mod common;
+
+use chrono::{Local, NaiveDate, TimeZone};
+use common::Harness;
+use fauzyrustproject::{notes::Notes, App, InputMode, TabData, Task};
+
+fn inbox(titles: &[&str]) -> App {
+    let tasks = titles.iter().map(|title| Task::new(*title)).collect();
+    App::with_tabs(vec![
+        TabData::new("Inbox", tasks),
+        TabData::new("Later", Vec::new()),
+    ])
+}
+
+#[test]
+fn add_complete_undo_quit_reload() {
+    let mut h = Harness::new(inbox(&[]));
+    h.keys("aBuy milk<enter>");
+    assert_eq!(h.titles(), ["Buy milk"]);
+    h.assert_screen_contains(">> Buy milk");
+
+    h.keys("<space>");
+    assert!(h.app.current_tab().list.items[0].done);
+    h.keys("u");
+    assert!(!h.app.current_tab().list.items[0].done);
+    h.keys("<ctrl+r>");
+    assert!(h.app.current_tab().list.items[0].done);
+
+    h.keys("q");
+    assert!(h.app.should_quit);
+
+    let h = h.reload();
+    assert_eq!(h.titles(), ["Buy milk"]);
+    assert!(h.app.current_tab().list.items[0].done);
+    assert_eq!(h.app.tabs.len(), 2);
+    h.assert_screen_contains("Buy milk");
+}
+
+#[test]
+fn keys_after_quit_are_ignored() {
+    let mut h = Harness::new(inbox(&["One"]));
+    h.keys("qaTwo<enter>");
+    assert!(h.app.should_quit);
+    assert_eq!(h.titles(), ["One"]);
+}
+
+#[test]
+fn escape_cancels_input_without_changes() {
+    let mut h = Harness::new(inbox(&["One"]));
+    h.keys("aTwo");
+    h.assert_screen_contains("New task");
+    h.keys("<esc>");
+    assert_eq!(h.app.input_mode, InputMode::Normal);
+    assert_eq!(h.titles(), ["One"]);
+    h.assert_screen_lacks("New task");
+}
+
+#[test]
+fn edit_and_delete_with_undo() {
+    let mut h = Harness::new(inbox(&["One", "Two"]));
+    // Belum ada tugas terpilih saat start
+    h.keys("<down><down>e");
+    h.keys(&"<backspace>".repeat(3));
+    h.keys("2<enter>");
+    assert_eq!(h.titles(), ["One", "2"]);
+
+    h.keys("d");
+    assert_eq!(h.titles(), ["One"]);
+    h.keys("uu");
+    assert_eq!(h.titles(), ["One", "Two"]);
+}
+
+#[test]
+fn command_line_creates_tab_and_adds_task() {
+    let mut h = Harness::new(inbox(&[]));
+    h.keys(":tabnew Errands<enter>");
+    assert_eq!(h.app.current_tab().name, "Errands");
+    h.keys(":add Call bank<enter>");
+    assert_eq!(h.titles(), ["Call bank"]);
+    h.assert_screen_contains("Errands");
+    h.assert_screen_contains("Call bank");
+
+    h.keys(":nope<enter>");
+    h.assert_screen_contains("unknown command: nope");
+    // Pesan hilang di tombol berikutnya
+    h.keys("<down>");
+    h.assert_screen_lacks("unknown command");
+}
+
+#[test]
+fn filter_hides_non_matching_tasks() {
+    let mut h = Harness::new(inbox(&["Buy milk", "Write report", "Buy bread"]));
+    h.keys("/buy<enter>");
+    h.assert_screen_contains("Tasks (filter: buy, 2/3)");
+    h.assert_screen_lacks("Write report");
+    h.keys("<esc>");
+    h.assert_screen_contains("Write report");
+}
+
+#[test]
+fn tag_sidebar_filters_by_tag() {
+    let mut h = Harness::new(inbox(&["Buy milk", "Write report", "Call mom"]));
+    h.keys("<down>#home<enter><down><down>#home<enter>");
+    assert_eq!(
+        h.titles(),
+        ["Buy milk #home", "Write report", "Call mom #home"]
+    );
+
+    h.keys("t");
+    h.assert_screen_contains("#home (2)");
+    h.keys("<down><enter>");
+    assert_eq!(h.app.current_tab().tag_filter.as_deref(), Some("home"));
+    h.assert_screen_contains("Tasks (#home, 2/3)");
+    h.assert_screen_lacks("Write report");
+}
+
+#[test]
+fn move_task_to_other_tab_and_undo() {
+    let mut h = Harness::new(inbox(&["One", "Two"]));
+    h.keys("<down>m2");
+    assert_eq!(h.titles(), ["Two"]);
+    assert_eq!(h.app.tabs[1].list.items[0].title, "One");
+    h.assert_screen_contains("Moved to Later");
+
+    h.keys("u");
+    assert_eq!(h.titles(), ["One", "Two"]);
+    assert!(h.app.tabs[1].list.items.is_empty());
+}
+
+#[test]
+fn help_popup_opens_and_closes() {
+    let mut h = Harness::new(inbox(&[]));
+    h.keys("?");
+    h.assert_screen_contains("Navigation");
+    h.keys("<esc>");
+    h.assert_screen_lacks("Navigation");
+}
+
+#[test]
+fn click_selects_task_and_tab() {
+    let mut h = Harness::new(inbox(&["One", "Two", "Three"]));
+    // Margin 1 + border: baris pertama daftar ada di y=5, tab di y=2
+    h.click(10, 7);
+    assert_eq!(h.app.current_tab().list.state.selected(), Some(2));
+    let (column, row) = h.find("Later").expect("tab title");
+    h.click(column, row);
+    assert_eq!(h.app.current_tab().name, "Later");
+}
+
+#[test]
+fn page_keys_move_selection_and_scrollbar() {
+    let titles: Vec<String> = (1..=60).map(|n| format!("Task {}", n)).collect();
+    let titles: Vec<&str> = titles.iter().map(String::as_str).collect();
+    let mut h = Harness::new(inbox(&titles));
+    let page = h.app.hit_areas.list.height as usize;
+    let list = h.app.hit_areas.list;
+    let scrollbar_x = list.right();
+    let thumb_rows = |h: &Harness| -> Vec<u16> {
+        (list.y..list.bottom())
+            .filter(|&y| h.terminal.backend().buffer().get(scrollbar_x, y).symbol == "█")
+            .collect()
+    };
+    assert_eq!(thumb_rows(&h).first(), Some(&list.y));
+
+    h.keys("<down><pagedown>");
+    assert_eq!(h.app.current_tab().list.state.selected(), Some(page));
+    h.assert_screen_contains(&format!(">> Task {}", page + 1));
+
+    h.keys("<end>");
+    assert_eq!(h.app.current_tab().list.state.selected(), Some(59));
+    h.assert_screen_contains(">> Task 60");
+    assert_eq!(thumb_rows(&h).last(), Some(&(list.bottom() - 1)));
+
+    h.keys("<pageup>");
+    assert_eq!(h.app.current_tab().list.state.selected(), Some(59 - page));
+    h.keys("<home>");
+    assert_eq!(h.app.current_tab().list.state.selected(), Some(0));
+    h.assert_screen_contains(">> Task 1 ");
+    assert_eq!(thumb_rows(&h).first(), Some(&list.y));
+}
+
+#[test]
+fn scrollbar_hidden_when_list_fits() {
+    let h = Harness::new(inbox(&["One", "Two"]));
+    h.assert_screen_lacks("█");
+}
+
+// Hanya baris yang terlihat digambar; klik tetap mengenai tugas di baris itu
+#[test]
+fn long_list_draws_and_clicks_the_visible_window() {
+    let titles: Vec<String> = (1..=5000).map(|n| format!("Task {}", n)).collect();
+    let titles: Vec<&str> = titles.iter().map(String::as_str).collect();
+    let mut h = Harness::new(inbox(&titles));
+    let list = h.app.hit_areas.list;
+    let page = list.height as usize;
+
+    h.keys("<down><end>");
+    h.assert_screen_contains(">> Task 5000");
+    h.assert_screen_contains(&format!("Task {} ", 5000 - page + 1));
+    h.assert_screen_lacks(&format!("Task {} ", 5000 - page));
+    assert_eq!(h.app.hit_areas.list_offset, 5000 - page);
+
+    h.click(list.x + 3, list.y);
+    assert_eq!(h.app.current_tab().list.state.selected(), Some(5000 - page));
+    h.assert_screen_contains(&format!(">> Task {} ", 5000 - page + 1));
+}
+
+#[test]
+fn filtered_list_follows_edits() {
+    let mut h = Harness::new(inbox(&["Buy milk", "Write report", "Buy bread"]));
+    h.keys("/buy<enter>");
+    h.assert_screen_contains("Tasks (filter: buy, 2/3)");
+    h.keys("<down>:edit Pay rent<enter>");
+    h.assert_screen_contains("Tasks (filter: buy, 1/3)");
+    h.assert_screen_lacks("Pay rent");
+    h.keys("u");
+    h.assert_screen_contains("Tasks (filter: buy, 2/3)");
+    h.assert_screen_contains("Buy milk");
+}
+
+#[test]
+fn export_and_import_todotxt() {
+    let mut h = Harness::new(inbox(&["Buy milk #home", "Write report"]));
+    let path = h.temp_path("todo.txt");
+    h.keys(":export tab ");
+    h.type_text(&path.display().to_string());
+    h.keys("<enter>");
+    h.assert_screen_contains("exported 2 tasks");
+    assert_eq!(std::fs::read_to_string(&path).unwrap().lines().count(), 2);
+
+    h.keys(":import ");
+    h.type_text(&path.display().to_string());
+    h.keys("<enter>");
+    h.assert_screen_contains("imported 2 tasks into 1 tabs");
+    assert_eq!(h.app.current_tab().name, "todo");
+    assert_eq!(h.titles(), ["Buy milk #home", "Write report"]);
+
+    h.keys(":import /nonexistent/todo.txt<enter>");
+    h.assert_screen_contains("import failed");
+}
+
+#[test]
+fn export_due_tasks_to_icalendar() {
+    let mut h = Harness::new(inbox(&["Buy milk #home"]));
+    h.keys("a");
+    h.type_text("Pay rent tomorrow");
+    h.keys("<enter>");
+    let path = h.temp_path("tasks.ics");
+    h.keys(":export tab ");
+    h.type_text(&path.display().to_string());
+    h.keys("<enter>");
+    // Hanya tugas dengan jatuh tempo yang masuk kalender
+    h.assert_screen_contains("exported 1 tasks");
+    let calendar = std::fs::read_to_string(&path).unwrap();
+    let tomorrow = h.app.now().date_naive().succ_opt().unwrap();
+    assert!(calendar.contains("SUMMARY:Pay rent\r\n"));
+    assert!(calendar.contains(&format!("DUE;VALUE=DATE:{}\r\n", tomorrow.format("%Y%m%d"))));
+    assert!(!calendar.contains("Buy milk"));
+}
+
+#[test]
+fn privacy_mode_hides_tag_names() {
+    let mut h = Harness::new(inbox(&["See #doctor", "Pay rent #home", "Call #doctor"]));
+    h.keys(":tags<enter>");
+    h.assert_screen_contains("#doctor (2)");
+
+    h.keys(":privacy<enter>");
+    h.assert_screen_lacks("doctor");
+    h.assert_screen_lacks("#home");
+    h.assert_screen_contains("(2)");
+    h.keys(":tag d");
+    assert!(h.app.command_completions().is_empty());
+}
+
+#[test]
+fn tag_sidebar_counts_follow_edits() {
+    let mut h = Harness::new(inbox(&["See #doctor", "Pay rent #home"]));
+    h.keys(":tags<enter>");
+    h.assert_screen_contains("#doctor (1)");
+
+    h.keys("a");
+    h.type_text("Call #doctor");
+    h.keys("<enter>");
+    h.assert_screen_contains("#doctor (2)");
+    h.keys("u");
+    h.assert_screen_contains("#doctor (1)");
+
+    // Hapus tag lewat edit judul
+    h.keys("<end>:edit Pay rent<enter>");
+    h.assert_screen_lacks("#home");
+}
+
+#[test]
+fn bench_data_has_no_tags() {
+    let mut app = fauzyrustproject::App::with_bench_data(200);
+    assert!(app.current_tab_mut().tag_counts().is_empty());
+}
+
+#[test]
+fn editing_changes_or_clears_the_due_date() {
+    let mut h = Harness::new(inbox(&["Meet on friday"]));
+    // Rabu, 1 Mei 2024
+    h.app.clock = Some(Local.with_ymd_and_hms(2024, 5, 1, 9, 0, 0).unwrap());
+    let due = |h: &Harness, index: usize| h.app.current_tab().list.items[index].due;
+    let date = |text: &str| NaiveDate::parse_from_str(text, "%Y-%m-%d").ok();
+
+    h.keys("a");
+    h.type_text("Pay rent fri");
+    h.keys("<enter>");
+    assert_eq!(due(&h, 1), date("2024-05-03"));
+
+    // Input edit berisi tanggalnya; disimpan tanpa diubah, tidak ada yang berubah
+    h.keys("e");
+    assert_eq!(h.app.input, "Pay rent 2024-05-03");
+    h.keys("<enter>");
+    assert_eq!(due(&h, 1), date("2024-05-03"));
+
+    h.keys(&format!("e{}", "<backspace>".repeat(10)));
+    h.type_text("mon");
+    h.keys("<enter>");
+    assert_eq!(h.titles()[1], "Pay rent");
+    assert_eq!(due(&h, 1), date("2024-05-06"));
+
+    // Tanggal dihapus dari input: jatuh tempo ikut dihapus
+    h.keys(&format!("e{}<enter>", "<backspace>".repeat(11)));
+    assert_eq!(h.titles()[1], "Pay rent");
+    assert_eq!(due(&h, 1), None);
+    h.keys("u");
+    assert_eq!(due(&h, 1), date("2024-05-06"));
+
+    h.keys(":edit Call landlord tomorrow<enter>");
+    assert_eq!(h.titles()[1], "Call landlord");
+    assert_eq!(due(&h, 1), date("2024-05-02"));
+
+    // Judul yang diakhiri nama hari tetap utuh kalau tidak disentuh
+    h.keys("<home>e<enter>");
+    assert_eq!(h.titles()[0], "Meet on friday");
+    assert_eq!(due(&h, 0), None);
+}
+
+#[test]
+fn statuses_command_sets_the_tab_workflow() {
+    let mut h = Harness::new(inbox(&["Write spec"]));
+    h.keys(":statuses Backlog, Ready,In progress,Done<enter>");
+    assert_eq!(
+        h.app.current_tab().statuses,
+        ["Backlog", "Ready", "In progress", "Done"]
+    );
+    // Tab lain tidak ikut berubah
+    assert!(h.app.tabs[1].statuses.is_empty());
+
+    h.keys("<down>]]:details<enter>");
+    h.assert_screen_contains("Status:  In progress");
+    h.keys("]");
+    assert!(h.app.current_tab().list.items[0].done);
+
+    h.keys(":statuses Done<enter>");
+    h.assert_screen_contains("need at least two different names");
+    assert_eq!(h.app.current_tab().statuses.len(), 4);
+
+    // Tanpa nama: kembali ke selesai/belum saja; tugas yang selesai tetap selesai
+    h.keys(":statuses<enter>");
+    assert!(h.app.current_tab().statuses.is_empty());
+    h.assert_screen_contains("Status:  Done");
+}
+
+#[test]
+fn in_place_edit_row_is_redacted_in_privacy_mode() {
+    let mut app = inbox(&["Call the clinic", "Pay rent"]);
+    app.edit_in_place = true;
+    let mut h = Harness::new(app);
+    h.keys(":privacy<enter><down>e");
+    h.type_text(" now");
+    assert_eq!(h.app.input, "Call the clinic now");
+    h.assert_screen_lacks("clinic");
+    h.assert_screen_contains(">> ••••••••");
+
+    h.keys("<enter>:privacy<enter>");
+    h.assert_screen_contains("Call the clinic now");
+}
+
+#[test]
+fn page_keys_scroll_details_when_shown() {
+    let titles: Vec<String> = (1..=80).map(|i| format!("Task {}", i)).collect();
+    let titles: Vec<&str> = titles.iter().map(String::as_str).collect();
+    let mut app = inbox(&titles);
+    app.current_tab_mut().list.items[0].notes = Notes::text(
+        (1..=100)
+            .map(|i| format!("note line {}", i))
+            .collect::<Vec<_>>()
+            .join("\n"),
+    );
+    let mut h = Harness::new(app);
+    let selected = |h: &Harness| h.app.current_tab().list.state.selected();
+
+    // Panel detail tampil: halaman panel bergeser, seleksi tetap
+    h.keys("<down><enter>");
+    h.assert_screen_contains("note line 1 ");
+    h.keys("<pagedown>");
+    assert_eq!(selected(&h), Some(0));
+    assert!(h.app.details.offset > 0);
+    h.assert_screen_lacks("note line 1 ");
+    h.keys("<pageup>");
+    assert_eq!(h.app.details.offset, 0);
+    assert_eq!(selected(&h), Some(0));
+
+    // Panel ditutup: halaman daftar tugas
+    h.keys("<enter><pagedown>");
+    assert!(selected(&h).unwrap() > 1);
+    h.keys("<pageup>");
+    assert_eq!(selected(&h), Some(0));
+}
+
+// Spinner hanya muncul kalau animasi aktif, dan frame-nya ikut waktu loading
+#[test]
+fn loading_spinner_follows_elapsed_time() {
+    use fauzyrustproject::{theme::Theme, ui};
+    use std::time::Duration;
+
+    let mut h = Harness::new(inbox(&[]));
+    let theme = Theme::default_dark();
+    let mut draw = |elapsed: Option<u64>| {
+        h.terminal
+            .draw(|f| {
+                let elapsed = elapsed.map(Duration::from_millis);
+                ui::draw_loading(f, &theme, "tasks.json", 0.5, elapsed)
+            })
+            .expect("draw");
+        h.screen()
+    };
+    let screen = draw(None);
+    assert!(screen.contains("Loading tasks.json"));
+    assert!(!screen.contains("⠋") && !screen.contains("⠙"));
+    assert!(draw(Some(0)).contains("⠋ Loading tasks.json"));
+    assert!(draw(Some(90)).contains("⠙ Loading tasks.json"));
+    // Sepuluh frame lalu berulang
+    assert!(draw(Some(800)).contains("⠋ Loading tasks.json"));
+}
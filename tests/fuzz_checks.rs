@@ -0,0 +1,114 @@
+// Pemeriksaan target fuzz dijalankan di sini dengan input tetap, supaya tetap
+// dikompilasi dan diuji oleh `cargo test` walau cargo-fuzz tidak terpasang.
+
+mod todotxt_import {
+    include!("../fuzz/checks/todotxt_import.rs");
+}
+
+mod command_line {
+    include!("../fuzz/checks/command_line.rs");
+}
+
+mod filter_query {
+    include!("../fuzz/checks/filter_query.rs");
+}
+
+// Potongan yang sering membuat parser tersandung, digabung acak tapi deterministik
+const PIECES: &[&str] = &[
+    "x",
+    "(A)",
+    "(Q)",
+    "2024-01-05",
+    "2024-1-5",
+    "due:2024-02-03",
+    "due:",
+    "pri:B",
+    "pri:",
+    "tab:a_b",
+    "tab:",
+    "+work",
+    "@home",
+    "#tag",
+    "Pay rent",
+    ":",
+    "\"",
+    "é",
+    " ",
+    "\n",
+    "\t",
+];
+
+fn generated(seed: u64, count: usize) -> Vec<String> {
+    let mut state = seed;
+    (0..count)
+        .map(|_| {
+            let mut text = String::new();
+            for _ in 0..12 {
+                state ^= state << 13;
+                state ^= state >> 7;
+                state ^= state << 17;
+                text.push_str(PIECES[(state % PIECES.len() as u64) as usize]);
+                if state % 3 == 0 {
+                    text.push(' ');
+                }
+            }
+            text
+        })
+        .collect()
+}
+
+#[test]
+fn todotxt_import_seeds() {
+    for text in [
+        "",
+        "x",
+        "x x",
+        "(A) 2024-05-01 Finish report +work due:2024-05-03",
+        "x 2024-01-05 2024-01-01 2024-01-09 Pay rent",
+        "due:2024-01-01 x marks the spot",
+        "tab:a_b (Q) pri:",
+        "a tab:One\nb tab:Two\n\n   \nc",
+    ] {
+        todotxt_import::check(text.as_bytes());
+    }
+    for text in generated(0x9e37_79b9_7f4a_7c15, 500) {
+        todotxt_import::check(text.as_bytes());
+    }
+    // Bukan UTF-8: dilewati, bukan panic
+    todotxt_import::check(&[0xff, 0xfe, b'x']);
+}
+
+#[test]
+fn command_line_seeds() {
+    command_line::check(
+        b"add Buy milk #home\n\
+          tabnew Errands\n\
+          tabrename Chores\n\
+          tabclose\n\
+          search milk\n\
+          sort priority\n\
+          copyto 2\n\
+          moveto Errands\n\
+          undo\n\
+          redo\n\
+          tags home\n\
+          theme light\n\
+          privacy\n\
+          nonsense command\n\
+          \n\
+          add",
+    );
+    for text in generated(7, 20) {
+        command_line::check(text.as_bytes());
+    }
+}
+
+#[test]
+fn filter_query_seeds() {
+    filter_query::check(b"milk\nhome\nBuy milk #home\nCall bank\nMilk run #home");
+    filter_query::check(b"\n\n");
+    filter_query::check(b"nothing matches\nmissing\nA\nB\nC");
+    for text in generated(42, 50) {
+        filter_query::check(text.as_bytes());
+    }
+}
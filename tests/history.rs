@@ -0,0 +1,126 @@
+use fauzyrustproject::{
+    history::{Action, History},
+    Task,
+};
+
+fn task(title: &str) -> Task {
+    Task {
+        created: None,
+        ..Task::new(title)
+    }
+}
+
+fn debug(action: &Action) -> String {
+    format!("{:?}", action)
+}
+
+fn actions() -> Vec<Action> {
+    vec![
+        Action::Add {
+            tab: 0,
+            index: 2,
+            task: task("Buy milk"),
+        },
+        Action::Delete {
+            tab: 1,
+            index: 0,
+            task: task("Call bank"),
+        },
+        Action::Update {
+            tab: 0,
+            index: 1,
+            before: task("Pay rent"),
+            after: Task {
+                done: true,
+                ..task("Pay rent")
+            },
+        },
+        Action::Transfer {
+            from_tab: 0,
+            from_index: 3,
+            to_tab: 2,
+            to_index: 0,
+            task: task("Water plants"),
+        },
+        Action::Swap {
+            tab: 0,
+            from: 1,
+            to: 2,
+        },
+        Action::Reorder {
+            tab: 1,
+            order: vec![2, 0, 3, 1],
+        },
+    ]
+}
+
+#[test]
+fn inverse_of_inverse_is_the_same_action() {
+    for action in actions() {
+        assert_eq!(debug(&action.clone().inverse().inverse()), debug(&action));
+    }
+}
+
+#[test]
+fn inverse_swaps_each_side() {
+    let inverses: Vec<String> = actions()
+        .into_iter()
+        .map(|action| debug(&action.inverse()))
+        .collect();
+    assert!(inverses[0].starts_with("Delete { tab: 0, index: 2,"));
+    assert!(inverses[1].starts_with("Add { tab: 1, index: 0,"));
+    assert_eq!(
+        inverses[2],
+        debug(&Action::Update {
+            tab: 0,
+            index: 1,
+            before: Task {
+                done: true,
+                ..task("Pay rent")
+            },
+            after: task("Pay rent"),
+        })
+    );
+    assert!(
+        inverses[3].starts_with("Transfer { from_tab: 2, from_index: 0, to_tab: 0, to_index: 3,")
+    );
+    assert_eq!(inverses[4], "Swap { tab: 0, from: 2, to: 1 }");
+}
+
+#[test]
+fn reorder_inverse_restores_the_original_order() {
+    // Tugas ke-i yang baru = tugas ke-order[i] yang lama
+    let apply = |items: &[char], order: &[usize]| -> Vec<char> {
+        order.iter().map(|&index| items[index]).collect()
+    };
+    let items = ['a', 'b', 'c', 'd'];
+    let order = vec![2, 0, 3, 1];
+    let sorted = apply(&items, &order);
+    assert_eq!(sorted, ['c', 'a', 'd', 'b']);
+    let Action::Reorder { order: inverse, .. } = Action::Reorder { tab: 0, order }.inverse() else {
+        panic!("reorder stays a reorder");
+    };
+    assert_eq!(apply(&sorted, &inverse), items);
+}
+
+#[test]
+fn undo_returns_the_inverse_and_redo_the_original() {
+    let mut history = History::default();
+    let add = actions().swap_remove(0);
+    history.record(add.clone());
+    assert_eq!(
+        history.undo().map(|action| debug(&action)),
+        Some(debug(&add.clone().inverse()))
+    );
+    assert!(history.undo().is_none());
+    assert_eq!(
+        history.redo().map(|action| debug(&action)),
+        Some(debug(&add))
+    );
+    assert!(history.redo().is_none());
+
+    // Perubahan baru membuang riwayat redo
+    history.undo();
+    history.record(actions().swap_remove(4));
+    assert!(history.redo().is_none());
+}
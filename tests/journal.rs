@@ -0,0 +1,111 @@
+mod common;
+
+use common::Harness;
+use fauzyrustproject::{
+    journal::{self, Entry, Journal},
+    storage, App,
+};
+use std::fs;
+
+fn names(entries: &[Entry]) -> Vec<String> {
+    entries
+        .iter()
+        .map(|entry| match entry {
+            Entry::NewTab(name) => name.clone(),
+            other => format!("{:?}", other),
+        })
+        .collect()
+}
+
+// Checksum isi file data seperti saat start; 0 kalau belum ada
+fn base(data: &std::path::Path) -> u64 {
+    storage::read(data)
+        .unwrap()
+        .map_or(0, |contents| journal::checksum(&contents))
+}
+
+#[test]
+fn journal_applies_to_the_data_it_was_started_on() {
+    let harness = Harness::new(App::new());
+    let data = harness.temp_path("tasks.json");
+    let path = Journal::path_for(&data);
+    storage::save(&data, &App::new().to_database()).expect("save");
+
+    let mut journal = Journal::create(&path, base(&data)).expect("create");
+    journal.append(&Entry::NewTab("Errands".into())).unwrap();
+    journal.append(&Entry::NewTab("Later".into())).unwrap();
+    drop(journal);
+
+    // Proses mati sebelum menyimpan: file data masih sama, entri diterapkan
+    let entries = journal::read(&path, base(&data)).unwrap().expect("journal");
+    assert_eq!(names(&entries), ["Errands", "Later"]);
+}
+
+#[test]
+fn journal_is_stale_once_the_data_file_was_saved() {
+    let harness = Harness::new(App::new());
+    let data = harness.temp_path("tasks.json");
+    let path = Journal::path_for(&data);
+    let mut app = App::new();
+    storage::save(&data, &app.to_database()).expect("save");
+
+    let mut journal = Journal::create(&path, base(&data)).expect("create");
+    journal.append(&Entry::NewTab("Errands".into())).unwrap();
+    drop(journal);
+
+    // Proses mati di antara simpan dan hapus journal: jangan diterapkan dua kali
+    app.replay(vec![Entry::NewTab("Errands".into())]);
+    storage::save(&data, &app.to_database()).expect("save");
+    let entries = journal::read(&path, base(&data)).unwrap();
+    assert_eq!(entries.map(|entries| entries.len()), Some(0));
+}
+
+#[test]
+fn missing_and_headerless_journals() {
+    let harness = Harness::new(App::new());
+    let data = harness.temp_path("tasks.json");
+    let path = Journal::path_for(&data);
+    assert!(storage::read(&data).unwrap().is_none());
+    assert_eq!(base(&data), 0);
+    assert!(journal::read(&path, 0).unwrap().is_none());
+
+    // Journal versi lama tanpa header tetap dibaca seperti dulu
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(&path, "{\"NewTab\":\"Old\"}\n{\"NewTab\":\"cut").unwrap();
+    let entries = journal::read(&path, 42).unwrap().expect("journal");
+    assert_eq!(names(&entries), ["Old"]);
+}
+
+#[test]
+fn status_changes_are_replayed() {
+    let harness = Harness::new(App::new());
+    let data = harness.temp_path("tasks.json");
+    let path = Journal::path_for(&data);
+    let mut journal = Journal::create(&path, 0).expect("create");
+    let statuses: Vec<String> = ["Todo", "Doing", "Done"].map(String::from).to_vec();
+    journal
+        .append(&Entry::SetStatuses {
+            tab: 1,
+            statuses: statuses.clone(),
+        })
+        .unwrap();
+    drop(journal);
+
+    let mut app = App::new();
+    app.replay(journal::read(&path, 0).unwrap().expect("journal"));
+    assert_eq!(app.tabs[1].statuses, statuses);
+    assert!(app.tabs[0].statuses.is_empty());
+}
+
+#[test]
+fn checksum_matches_what_save_writes() {
+    let harness = Harness::new(App::new());
+    let data = harness.temp_path("tasks.json");
+    let database = App::new().to_database();
+    let contents = storage::to_bytes(&database).unwrap();
+    storage::save(&data, &database).expect("save");
+    assert_eq!(base(&data), journal::checksum(&contents));
+    // Isi yang sama dipakai untuk parse
+    let loaded = storage::parse_with_progress(&contents, |_, _| {}).unwrap();
+    assert_eq!(loaded.tabs.len(), database.tabs.len());
+}
@@ -0,0 +1,82 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use fauzyrustproject::keymap::KeyChord;
+
+fn chord(text: &str) -> KeyChord {
+    KeyChord::parse(text).unwrap_or_else(|err| panic!("{}", err))
+}
+
+// Ditulis ulang dengan Display lalu dibaca lagi harus menghasilkan tombol yang sama
+#[test]
+fn display_round_trips_through_parse() {
+    for text in [
+        "a",
+        "T",
+        "?",
+        "+",
+        "ctrl++",
+        "space",
+        "ctrl+space",
+        "enter",
+        "esc",
+        "tab",
+        "shift+tab",
+        "backspace",
+        "delete",
+        "insert",
+        "up",
+        "down",
+        "left",
+        "right",
+        "home",
+        "end",
+        "pageup",
+        "pagedown",
+        "f1",
+        "f12",
+        "f24",
+        "ctrl+b",
+        "alt+x",
+        "ctrl+alt+delete",
+        "shift+up",
+    ] {
+        let parsed = chord(text);
+        assert_eq!(parsed.to_string(), text);
+        assert_eq!(chord(&parsed.to_string()), parsed, "{}", text);
+    }
+}
+
+#[test]
+fn aliases_are_normalised() {
+    for (alias, canonical) in [
+        ("Control+b", "ctrl+b"),
+        ("ESCAPE", "esc"),
+        ("return", "enter"),
+        ("del", "delete"),
+        ("backtab", "shift+tab"),
+        // Shift dengan huruf sama dengan huruf besar
+        ("shift+t", "T"),
+    ] {
+        assert_eq!(chord(alias).to_string(), canonical, "{}", alias);
+        assert_eq!(chord(alias), chord(canonical));
+    }
+}
+
+#[test]
+fn chords_match_terminal_events() {
+    // Terminal mengirim huruf besar dengan shift; shift diabaikan untuk karakter
+    let event = KeyEvent::new(KeyCode::Char('T'), KeyModifiers::SHIFT);
+    assert_eq!(KeyChord::from(event), chord("T"));
+    let event = KeyEvent::new(KeyCode::BackTab, KeyModifiers::SHIFT);
+    assert_eq!(KeyChord::from(event), chord("shift+tab"));
+    let event = KeyEvent::new(KeyCode::Char('r'), KeyModifiers::CONTROL);
+    assert_eq!(KeyChord::from(event), chord("ctrl+r"));
+}
+
+#[test]
+fn invalid_keys_are_rejected() {
+    // Plus di akhir selalu berarti tombol plus
+    assert_eq!(chord("ctrl+"), chord("ctrl++"));
+    for text in ["", "hyper+a", "f0", "f25", "pgup", "ab"] {
+        assert!(KeyChord::parse(text).is_err(), "{:?}", text);
+    }
+}
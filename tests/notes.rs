@@ -0,0 +1,119 @@
+mod common;
+
+use common::Harness;
+use fauzyrustproject::{
+    notes::{NoteFile, Notes},
+    storage, App, TabData, Task,
+};
+use std::{fs, rc::Rc};
+
+fn inbox(titles: &[&str]) -> App {
+    let tasks = titles.iter().map(|title| Task::new(*title)).collect();
+    App::with_tabs(vec![TabData::new("Inbox", tasks)])
+}
+
+#[test]
+fn saved_notes_live_in_the_notes_file() {
+    let mut h = Harness::new(inbox(&["Call bank"]));
+    h.keys("<down>:notes Ask about the fee<enter>");
+    let mut h = h.reload();
+
+    // File data hanya menyimpan posisinya; teksnya tidak ikut dimuat
+    assert_eq!(h.app.current_tab().list.items[0].notes.loaded(), None);
+    let data = fs::read_to_string(h.temp_path("tasks.json")).unwrap();
+    assert!(!data.contains("Ask about"));
+    assert!(data.contains("\"notes_file\": \"tasks.json.notes\""));
+    assert_eq!(
+        fs::read_to_string(h.temp_path("tasks.json.notes")).unwrap(),
+        "Ask about the fee"
+    );
+
+    h.keys("<down><enter>");
+    h.assert_screen_contains("Ask about the fee");
+    // Isi yang sama bukan perubahan, jadi tidak masuk riwayat undo
+    h.keys(":notes Ask about the fee<enter>");
+    assert!(h.app.history.undo().is_none());
+
+    let database = h.app.to_database_with_notes().unwrap();
+    assert_eq!(database.notes_file, None);
+    assert_eq!(
+        database.tabs[0].tasks[0].notes.loaded(),
+        Some("Ask about the fee")
+    );
+}
+
+#[test]
+fn edited_notes_are_appended_and_undo_restores_the_old_text() {
+    let mut h = Harness::new(inbox(&["Call bank"]));
+    h.keys("<down>:notes First<enter>");
+    let mut h = h.reload();
+    h.keys("<down>:notes Second<enter>");
+    let mut h = h.reload();
+    assert_eq!(
+        fs::read_to_string(h.temp_path("tasks.json.notes")).unwrap(),
+        "FirstSecond"
+    );
+
+    h.keys("<down>:notes Third<enter>u<enter>");
+    h.assert_screen_contains("Second");
+    h.assert_screen_lacks("Third");
+}
+
+#[test]
+fn inline_notes_from_older_files_still_load() {
+    let database = storage::parse_with_progress(
+        br#"{"tabs": [{"name": "Inbox", "tasks": [{"title": "Call bank", "notes": "Ask about the fee"}]}]}"#,
+        |_, _| {},
+    )
+    .unwrap();
+    assert_eq!(database.notes_file, None);
+    let notes = &database.tabs[0].tasks[0].notes;
+    assert_eq!(notes.loaded(), Some("Ask about the fee"));
+}
+
+#[test]
+fn tasks_share_one_copy_of_each_status_name() {
+    let database = storage::parse_with_progress(
+        br#"{"tabs": [{"name": "Board", "statuses": ["Todo", "Review", "Done"], "tasks": [
+            {"title": "A", "status": "Review"},
+            {"title": "B", "status": "Review"},
+            {"title": "C"}
+        ]}]}"#,
+        |_, _| {},
+    )
+    .unwrap();
+    let mut tasks = database.tabs.into_iter().next().unwrap().tasks;
+    tasks[2].set_status(&["Todo".into(), "Review".into(), "Done".into()], 1);
+    let [a, b, c] = [0, 1, 2].map(|index| tasks[index].status.clone().unwrap());
+    assert_eq!(&*a, "Review");
+    assert!(Rc::ptr_eq(&a, &b) && Rc::ptr_eq(&a, &c));
+}
+
+#[test]
+fn notes_file_is_compacted_once_mostly_garbage() {
+    let h = Harness::new(App::new());
+    let data = h.temp_path("tasks.json");
+    let mut file = NoteFile::for_data(&data, None);
+    let long = "x".repeat(40 * 1024);
+    let mut notes = vec![Notes::text(long.clone()), Notes::text("keep me")];
+    file.store(&mut notes).unwrap();
+
+    // Setiap edit menambah teks baru di akhir; yang lama jadi sampah
+    for round in 0..4 {
+        notes[0] = Notes::text(format!("{}{}", round, long));
+        file.store(&mut notes).unwrap();
+    }
+    assert!(fs::metadata(file.path()).unwrap().len() < 3 * 41 * 1024);
+    assert_eq!(file.read(&notes[0]).unwrap(), format!("3{}", long));
+    assert_eq!(file.read(&notes[1]).unwrap(), "keep me");
+
+    // File lama baru dihapus pada simpan berikutnya, setelah file data menunjuk file baru
+    file.store(&mut notes).unwrap();
+    let files: Vec<_> = fs::read_dir(data.parent().unwrap())
+        .unwrap()
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.file_name().to_string_lossy().contains(".notes"))
+        .collect();
+    assert_eq!(files.len(), 1);
+    assert_eq!(file.read(&notes[1]).unwrap(), "keep me");
+}
@@ -0,0 +1,197 @@
+mod common;
+
+use chrono::Local;
+use common::Harness;
+use crossterm::event::{KeyEvent, MouseButton, MouseEvent, MouseEventKind};
+use fauzyrustproject::{
+    event::{self, handle_key, handle_mouse, AppEvent, Events},
+    keymap::KeyChord,
+    session::{self, Header, Record, Recorder},
+    App, Task,
+};
+use std::{fs, io::Write, time::Duration};
+
+fn key(chord: &str) -> AppEvent {
+    AppEvent::Key(KeyEvent::from(KeyChord::parse(chord).expect("key chord")))
+}
+
+fn header(app: &App) -> Header {
+    Header {
+        version: session::VERSION,
+        started: Local::now(),
+        size: (80, 24),
+        tick_rate_ms: 250,
+        config: Some("vim = true\n".to_string()),
+        edit_in_place: false,
+        lock_after_secs: None,
+        database: app.to_database(),
+    }
+}
+
+// Sama seperti loop utama, tanpa terminal sungguhan
+fn apply(harness: &mut Harness, event: AppEvent) {
+    match event {
+        AppEvent::Key(key) => handle_key(&mut harness.app, key),
+        AppEvent::Mouse(mouse) => handle_mouse(&mut harness.app, mouse),
+        AppEvent::Resize(_, _) | AppEvent::Tick => {}
+    }
+    harness.draw();
+}
+
+fn sample_app() -> App {
+    let mut app = App::new();
+    for title in ["Water plants", "Pay rent #home", "Call bank"] {
+        app.current_tab_mut().list.items.push(Task::new(title));
+    }
+    app
+}
+
+#[test]
+fn replaying_a_recorded_session_ends_in_the_same_state() {
+    let mut recording = Harness::new(sample_app());
+    let path = recording.temp_path("session.log");
+    let mut recorder = Recorder::create(&path, &header(&recording.app)).expect("create log");
+
+    let (column, row) = recording.find("Call bank").expect("task on screen");
+    let click = AppEvent::Mouse(MouseEvent {
+        kind: MouseEventKind::Down(MouseButton::Left),
+        column,
+        row,
+        modifiers: crossterm::event::KeyModifiers::NONE,
+    });
+    let mut events = vec![key("down"), key("down"), key("space"), key("a")];
+    events.extend("Buy milk".chars().map(|c| key(&c.to_string())));
+    events.extend([key("enter"), key("+"), click, key("J")]);
+    for event in events {
+        recording.app.clock = Some(recorder.record(event).expect("record"));
+        apply(&mut recording, event);
+    }
+
+    let (header, records) = session::read(&path).expect("read log");
+    assert_eq!(header.config.as_deref(), Some("vim = true\n"));
+    assert_eq!(records.len(), 16);
+    assert!(records
+        .windows(2)
+        .all(|pair| pair[0].at_ms <= pair[1].at_ms));
+
+    let mut replay = Harness::new(App::from_database(header.database.clone()));
+    for record in records {
+        replay.app.clock = Some(session::time_at(header.started, record.at_ms));
+        apply(&mut replay, record.event);
+    }
+    assert_eq!(replay.titles(), recording.titles());
+    assert_eq!(replay.screen(), recording.screen());
+    assert_eq!(
+        serde_json::to_string(&replay.app.to_database()).unwrap(),
+        serde_json::to_string(&recording.app.to_database()).unwrap()
+    );
+}
+
+#[test]
+fn truncated_last_line_is_ignored() {
+    let harness = Harness::new(sample_app());
+    let path = harness.temp_path("crashed.log");
+    let mut recorder = Recorder::create(&path, &header(&harness.app)).expect("create log");
+    recorder.record(key("down")).expect("record");
+    recorder.record(key("x")).expect("record");
+    drop(recorder);
+    // Proses mati di tengah menulis baris berikutnya
+    fs::OpenOptions::new()
+        .append(true)
+        .open(&path)
+        .and_then(|mut file| file.write_all(b"{\"at_ms\":12,\"ev"))
+        .expect("append");
+
+    let (_, records) = session::read(&path).expect("read log");
+    let events: Vec<AppEvent> = records.iter().map(|record| record.event).collect();
+    assert_eq!(events, [key("down"), key("x")]);
+}
+
+#[test]
+fn other_versions_and_empty_logs_are_rejected() {
+    let harness = Harness::new(App::new());
+    let path = harness.temp_path("future.log");
+    let future = Header {
+        version: session::VERSION + 1,
+        ..header(&harness.app)
+    };
+    Recorder::create(&path, &future).expect("create log");
+    let err = session::read(&path).unwrap_err();
+    assert!(err.to_string().contains("not supported"), "{}", err);
+
+    let empty = harness.temp_path("empty.log");
+    fs::write(&empty, "").expect("write");
+    assert!(session::read(&empty).is_err());
+}
+
+#[test]
+fn replayed_events_come_back_in_order() {
+    let records = vec![
+        Record {
+            at_ms: 0,
+            event: key("j"),
+        },
+        Record {
+            at_ms: 0,
+            event: AppEvent::Resize(100, 30),
+        },
+        Record {
+            at_ms: 0,
+            event: key("q"),
+        },
+    ];
+    let started = Local::now();
+    let mut events = Events::replay(Duration::from_secs(60), started, records);
+    assert_eq!(events.replay_clock(), Some(started));
+    assert!(events.is_replaying());
+    assert_eq!(events.next_event().unwrap(), key("j"));
+    assert_eq!(events.next_event().unwrap(), AppEvent::Resize(100, 30));
+    assert_eq!(events.next_event().unwrap(), key("q"));
+    assert!(!events.is_replaying());
+    // Jam tetap dipegang sampai replay ditutup, supaya input terakhir pun memakainya
+    assert_eq!(events.replay_clock(), Some(started));
+
+    let mut stopped = Events::replay(
+        Duration::from_secs(60),
+        started,
+        vec![Record {
+            at_ms: 0,
+            event: key("j"),
+        }],
+    );
+    stopped.stop_replay();
+    assert!(!stopped.is_replaying());
+    assert_eq!(stopped.replay_clock(), None);
+}
+
+#[test]
+fn only_key_presses_reach_the_app() {
+    use crossterm::event::{Event, KeyEventKind};
+    let press = KeyEvent::from(KeyChord::parse("j").unwrap());
+    // Windows mengirim tekan dan lepas untuk setiap tombol
+    let release = KeyEvent {
+        kind: KeyEventKind::Release,
+        ..press
+    };
+    let repeat = KeyEvent {
+        kind: KeyEventKind::Repeat,
+        ..press
+    };
+    assert_eq!(
+        event::from_terminal(Event::Key(press)),
+        Some(AppEvent::Key(press))
+    );
+    assert_eq!(event::from_terminal(Event::Key(release)), None);
+    assert_eq!(event::from_terminal(Event::Key(repeat)), None);
+    assert_eq!(
+        event::from_terminal(Event::Resize(80, 24)),
+        Some(AppEvent::Resize(80, 24))
+    );
+    let moved = MouseEvent {
+        kind: MouseEventKind::Moved,
+        column: 0,
+        row: 0,
+        modifiers: crossterm::event::KeyModifiers::NONE,
+    };
+    assert_eq!(event::from_terminal(Event::Mouse(moved)), None);
+}
@@ -0,0 +1,158 @@
+use fauzyrustproject::StatefulList;
+use proptest::prelude::*;
+
+// Operasi acak terhadap daftar; index sengaja boleh melewati panjang daftar
+#[derive(Debug, Clone)]
+enum Op {
+    Next,
+    Previous,
+    Insert(usize),
+    Remove(usize),
+    Swap(usize, usize),
+    Clamp(usize),
+    Select(Option<usize>),
+}
+
+fn op() -> impl Strategy<Value = Op> {
+    prop_oneof![
+        Just(Op::Next),
+        Just(Op::Previous),
+        (0..12usize).prop_map(Op::Insert),
+        (0..12usize).prop_map(Op::Remove),
+        (0..12usize, 0..12usize).prop_map(|(a, b)| Op::Swap(a, b)),
+        (0..12usize).prop_map(Op::Clamp),
+        proptest::option::of(0..12usize).prop_map(Op::Select),
+    ]
+}
+
+// Item unik supaya bisa dicek apakah seleksi tetap di item yang sama
+fn list(len: usize) -> StatefulList<u32> {
+    StatefulList::new((0..len as u32).collect())
+}
+
+fn selected_item(list: &StatefulList<u32>) -> Option<u32> {
+    list.state.selected().map(|i| list.items[i])
+}
+
+fn in_bounds(list: &StatefulList<u32>) -> bool {
+    list.state.selected().map_or(true, |i| i < list.items.len())
+}
+
+proptest! {
+    #[test]
+    fn selection_stays_in_bounds(len in 0..8usize, ops in prop::collection::vec(op(), 0..64)) {
+        let mut list = list(len);
+        let mut next_item = len as u32;
+        for op in ops {
+            match op {
+                Op::Next => list.next(),
+                Op::Previous => list.previous(),
+                Op::Insert(index) => {
+                    list.insert(index, next_item);
+                    next_item += 1;
+                }
+                Op::Remove(index) => {
+                    list.remove(index);
+                }
+                Op::Swap(a, b) => {
+                    list.swap(a, b);
+                }
+                Op::Clamp(rows) => list.clamp(rows),
+                Op::Select(index) => {
+                    list.state.select(index.filter(|&i| i < list.items.len()));
+                }
+            }
+            prop_assert!(
+                in_bounds(&list),
+                "{:?} out of {}",
+                list.state.selected(),
+                list.items.len()
+            );
+        }
+    }
+
+    #[test]
+    fn next_and_previous_wrap_around(len in 1..8usize, start in 0..8usize) {
+        let mut list = list(len);
+        let start = start % len;
+        list.state.select(Some(start));
+        list.next();
+        prop_assert_eq!(list.state.selected(), Some((start + 1) % len));
+        list.previous();
+        prop_assert_eq!(list.state.selected(), Some(start));
+        list.previous();
+        prop_assert_eq!(list.state.selected(), Some((start + len - 1) % len));
+    }
+
+    #[test]
+    fn next_and_previous_on_empty_list_do_nothing(forward in any::<bool>()) {
+        let mut list = list(0);
+        if forward {
+            list.next();
+        } else {
+            list.previous();
+        }
+        prop_assert_eq!(list.state.selected(), None);
+    }
+
+    // `items` bisa diganti langsung (misalnya saat sorting) sehingga seleksi tertinggal
+    #[test]
+    fn next_and_previous_recover_from_stale_selection(
+        len in 1..8usize,
+        stale in 0..16usize,
+        forward in any::<bool>(),
+    ) {
+        let mut list = list(len);
+        list.state.select(Some(len + stale));
+        if forward {
+            list.next();
+        } else {
+            list.previous();
+        }
+        prop_assert!(in_bounds(&list));
+    }
+
+    #[test]
+    fn insert_keeps_selected_item(len in 1..8usize, selected in 0..8usize, index in 0..12usize) {
+        let mut list = list(len);
+        list.state.select(Some(selected % len));
+        let before = selected_item(&list);
+        let inserted = list.insert(index, 100);
+        prop_assert_eq!(selected_item(&list), before);
+        prop_assert_eq!(list.items[inserted], 100);
+    }
+
+    #[test]
+    fn remove_keeps_selected_item_or_neighbour(
+        len in 1..8usize,
+        selected in 0..8usize,
+        index in 0..8usize,
+    ) {
+        let mut list = list(len);
+        let selected = selected % len;
+        let index = index % len;
+        list.state.select(Some(selected));
+        let before = selected_item(&list);
+        prop_assert_eq!(list.remove(index), Some(index as u32));
+        prop_assert!(in_bounds(&list));
+        if list.items.is_empty() {
+            prop_assert_eq!(list.state.selected(), None);
+        } else if index != selected {
+            prop_assert_eq!(selected_item(&list), before);
+        }
+    }
+
+    #[test]
+    fn swap_keeps_selected_item(
+        len in 1..8usize,
+        selected in 0..8usize,
+        a in 0..8usize,
+        b in 0..8usize,
+    ) {
+        let mut list = list(len);
+        list.state.select(Some(selected % len));
+        let before = selected_item(&list);
+        prop_assert!(list.swap(a % len, b % len));
+        prop_assert_eq!(selected_item(&list), before);
+    }
+}